@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::game::controls::resources::InputAction;
+
+#[derive(Component)]
+pub struct ControlsMenu {}
+
+#[derive(Component)]
+pub struct RebindButton {
+    pub action: InputAction,
+}
+
+#[derive(Component)]
+pub struct ClearBindingsButton {
+    pub action: InputAction,
+}
+
+#[derive(Component)]
+pub struct BindingsText {
+    pub action: InputAction,
+}
+
+#[derive(Component)]
+pub struct ControlModeButton {}
+
+#[derive(Component)]
+pub struct ControlModeText {}
+
+#[derive(Component)]
+pub struct ResetBindingsButton {}
+
+#[derive(Component)]
+pub struct BackButton {}
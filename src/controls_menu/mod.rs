@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+mod components;
+mod resources;
+mod styles;
+mod systems;
+
+use crate::states::AppState;
+use resources::RebindCapture;
+use systems::interactions::*;
+use systems::layout::*;
+
+pub struct ControlsMenuPlugin;
+
+impl Plugin for ControlsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RebindCapture>()
+            .add_systems(OnEnter(AppState::ControlsMenu), spawn_controls_menu)
+            .add_systems(
+                Update,
+                (
+                    // Captures before the buttons so the click starting a capture isn't bound
+                    capture_rebinding,
+                    interact_with_rebind_buttons,
+                    interact_with_clear_buttons,
+                    interact_with_control_mode_button,
+                    interact_with_reset_button,
+                    interact_with_back_button,
+                    update_rebind_buttons,
+                    update_control_mode_text,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::ControlsMenu)),
+            )
+            .add_systems(
+                OnExit(AppState::ControlsMenu),
+                (despawn_controls_menu, cancel_rebinding),
+            );
+    }
+}
@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use crate::game::controls::resources::InputAction;
+
+/// The action waiting for the next input pressed to be bound to it
+#[derive(Resource, Default)]
+pub struct RebindCapture(pub Option<InputAction>);
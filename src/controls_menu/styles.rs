@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+
+pub const NORMAL_BUTTON_COLOR: Color = Color::rgb(0.15, 0.15, 0.15);
+pub const HOVERED_BUTTON_COLOR: Color = Color::rgb(0.25, 0.25, 0.25);
+pub const PRESSED_BUTTON_COLOR: Color = Color::rgb(0.35, 0.75, 0.35);
+pub const CAPTURING_BUTTON_COLOR: Color = Color::rgb(0.75, 0.55, 0.15);
+
+pub fn controls_menu_style() -> Style {
+    Style {
+        flex_direction: FlexDirection::Column,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        width: Val::Percent(100.),
+        height: Val::Percent(100.),
+        row_gap: Val::Px(8.),
+        column_gap: Val::Px(8.),
+        ..Default::default()
+    }
+}
+
+pub fn title_style() -> Style {
+    Style {
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        width: Val::Px(300.),
+        height: Val::Px(100.),
+        ..Default::default()
+    }
+}
+
+pub fn row_style() -> Style {
+    Style {
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.),
+        ..Default::default()
+    }
+}
+
+pub fn action_label_style() -> Style {
+    Style {
+        width: Val::Px(180.),
+        ..Default::default()
+    }
+}
+
+pub fn bindings_button_style() -> Style {
+    Style {
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        width: Val::Px(520.),
+        height: Val::Px(40.),
+        ..Default::default()
+    }
+}
+
+pub fn small_button_style() -> Style {
+    Style {
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        width: Val::Px(100.),
+        height: Val::Px(40.),
+        ..Default::default()
+    }
+}
+
+pub fn button_style() -> Style {
+    Style {
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        width: Val::Px(200.),
+        height: Val::Px(80.),
+        ..Default::default()
+    }
+}
+
+pub fn get_title_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+    TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 64.0,
+        color: Color::WHITE,
+    }
+}
+
+pub fn get_button_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+    TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 32.0,
+        color: Color::WHITE,
+    }
+}
+
+pub fn get_row_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+    TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: 22.0,
+        color: Color::WHITE,
+    }
+}
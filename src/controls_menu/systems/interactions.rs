@@ -0,0 +1,205 @@
+use bevy::prelude::*;
+
+use crate::controls_menu::components::*;
+use crate::controls_menu::resources::RebindCapture;
+use crate::controls_menu::styles::*;
+use crate::controls_menu::systems::layout::{bindings_label, control_mode_label};
+use crate::game::controls::resources::{
+    ActiveGamepad, Binding, ControlSettings, InputBindings, InputSources,
+};
+use crate::AppState;
+
+fn save_bindings(bindings: &InputBindings) {
+    if let Err(e) = bindings.save() {
+        warn!("Failed to save bindings: {e}");
+    }
+}
+
+pub fn capture_rebinding(
+    mut capture: ResMut<RebindCapture>,
+    mut bindings: ResMut<InputBindings>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    active_gamepad: Res<ActiveGamepad>,
+) {
+    if let Some(action) = capture.0 {
+        let inputs = InputSources {
+            keyboard: &keyboard_input,
+            mouse: &mouse_input,
+            gamepad_buttons: &gamepad_buttons,
+            gamepad: active_gamepad.0,
+        };
+        match Binding::first_just_pressed(&inputs) {
+            Some(Binding::Key(KeyCode::Escape)) => {
+                capture.0 = None;
+            }
+            Some(binding) => {
+                bindings.add(action, binding);
+                save_bindings(&bindings);
+                capture.0 = None;
+            }
+            None => {}
+        }
+    }
+}
+
+pub fn cancel_rebinding(mut capture: ResMut<RebindCapture>) {
+    capture.0 = None;
+}
+
+pub fn interact_with_rebind_buttons(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &RebindButton),
+        Changed<Interaction>,
+    >,
+    mut capture: ResMut<RebindCapture>,
+) {
+    for (interaction, mut background_color, rebind_button) in button_query.iter_mut() {
+        if capture.0 == Some(rebind_button.action) {
+            continue;
+        }
+        match *interaction {
+            Interaction::Pressed => {
+                capture.0 = Some(rebind_button.action);
+            }
+            Interaction::Hovered => {
+                *background_color = HOVERED_BUTTON_COLOR.into();
+            }
+            Interaction::None => {
+                *background_color = NORMAL_BUTTON_COLOR.into();
+            }
+        }
+    }
+}
+
+pub fn interact_with_clear_buttons(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &ClearBindingsButton),
+        Changed<Interaction>,
+    >,
+    mut bindings: ResMut<InputBindings>,
+) {
+    for (interaction, mut background_color, clear_button) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                bindings.clear(clear_button.action);
+                save_bindings(&bindings);
+            }
+            Interaction::Hovered => {
+                *background_color = HOVERED_BUTTON_COLOR.into();
+            }
+            Interaction::None => {
+                *background_color = NORMAL_BUTTON_COLOR.into();
+            }
+        }
+    }
+}
+
+pub fn interact_with_control_mode_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ControlModeButton>),
+    >,
+    mut control_settings: ResMut<ControlSettings>,
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                control_settings.mode = control_settings.mode.next();
+                if let Err(e) = control_settings.save() {
+                    warn!("Failed to save control settings: {e}");
+                }
+            }
+            Interaction::Hovered => {
+                *background_color = HOVERED_BUTTON_COLOR.into();
+            }
+            Interaction::None => {
+                *background_color = NORMAL_BUTTON_COLOR.into();
+            }
+        }
+    }
+}
+
+pub fn interact_with_reset_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<ResetBindingsButton>),
+    >,
+    mut bindings: ResMut<InputBindings>,
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                *bindings = InputBindings::default();
+                save_bindings(&bindings);
+            }
+            Interaction::Hovered => {
+                *background_color = HOVERED_BUTTON_COLOR.into();
+            }
+            Interaction::None => {
+                *background_color = NORMAL_BUTTON_COLOR.into();
+            }
+        }
+    }
+}
+
+pub fn interact_with_back_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<BackButton>),
+    >,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::MainMenu);
+            }
+            Interaction::Hovered => {
+                *background_color = HOVERED_BUTTON_COLOR.into();
+            }
+            Interaction::None => {
+                *background_color = NORMAL_BUTTON_COLOR.into();
+            }
+        }
+    }
+}
+
+pub fn update_rebind_buttons(
+    capture: Res<RebindCapture>,
+    bindings: Res<InputBindings>,
+    mut text_query: Query<(&mut Text, &BindingsText)>,
+    mut button_query: Query<(&mut BackgroundColor, &RebindButton)>,
+) {
+    if !capture.is_changed() && !bindings.is_changed() {
+        return;
+    }
+    for (mut text, bindings_text) in text_query.iter_mut() {
+        let capturing = capture.0 == Some(bindings_text.action);
+        text.sections[0].value = bindings_label(&bindings, bindings_text.action, capturing);
+    }
+    for (mut background_color, rebind_button) in button_query.iter_mut() {
+        *background_color = if capture.0 == Some(rebind_button.action) {
+            CAPTURING_BUTTON_COLOR.into()
+        } else {
+            NORMAL_BUTTON_COLOR.into()
+        };
+    }
+}
+
+pub fn update_control_mode_text(
+    control_settings: Res<ControlSettings>,
+    mut text_query: Query<&mut Text, With<ControlModeText>>,
+) {
+    if !control_settings.is_changed() {
+        return;
+    }
+    if let Ok(mut text) = text_query.get_single_mut() {
+        text.sections[0].value = control_mode_label(control_settings.mode);
+    }
+}
@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+
+use crate::controls_menu::components::*;
+use crate::controls_menu::styles::*;
+use crate::game::controls::resources::{ControlMode, ControlSettings, InputAction, InputBindings};
+
+pub fn spawn_controls_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    bindings: Res<InputBindings>,
+    control_settings: Res<ControlSettings>,
+) {
+    build_controls_menu(&mut commands, &asset_server, &bindings, &control_settings);
+}
+
+pub fn despawn_controls_menu(
+    mut commands: Commands,
+    controls_menu_query: Query<Entity, With<ControlsMenu>>,
+) {
+    if let Ok(controls_menu_entity) = controls_menu_query.get_single() {
+        commands.entity(controls_menu_entity).despawn_recursive();
+    }
+}
+
+pub fn bindings_label(bindings: &InputBindings, action: InputAction, capturing: bool) -> String {
+    if capturing {
+        return "Press a key or button... (Esc to cancel)".to_string();
+    }
+    let labels = bindings
+        .get(action)
+        .iter()
+        .map(|binding| binding.to_string())
+        .collect::<Vec<String>>();
+    if labels.is_empty() {
+        "Unbound".to_string()
+    } else {
+        labels.join(", ")
+    }
+}
+
+pub fn control_mode_label(mode: ControlMode) -> String {
+    format!("Steering: {}", mode.label())
+}
+
+pub fn build_controls_menu(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    bindings: &InputBindings,
+    control_settings: &ControlSettings,
+) -> Entity {
+    let controls_menu_entity = commands
+        .spawn(NodeBundle {
+            style: controls_menu_style(),
+            ..default()
+        })
+        .insert(ControlsMenu {})
+        .with_children(|parent| {
+            // Title
+            parent
+                .spawn(NodeBundle {
+                    style: title_style(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text {
+                            sections: vec![TextSection::new(
+                                "Controls",
+                                get_title_text_style(asset_server),
+                            )],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
+                        ..default()
+                    });
+                });
+            // ==== Control Mode Button ====
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: bindings_button_style(),
+                        background_color: NORMAL_BUTTON_COLOR.into(),
+                        ..default()
+                    },
+                    ControlModeButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle {
+                            text: Text::from_section(
+                                control_mode_label(control_settings.mode),
+                                get_row_text_style(asset_server),
+                            ),
+                            ..default()
+                        },
+                        ControlModeText {},
+                    ));
+                });
+            // ==== Action Rows ====
+            for action in InputAction::ALL {
+                parent
+                    .spawn(NodeBundle {
+                        style: row_style(),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle {
+                            style: action_label_style(),
+                            text: Text::from_section(
+                                action.label(),
+                                get_row_text_style(asset_server),
+                            ),
+                            ..default()
+                        });
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: bindings_button_style(),
+                                    background_color: NORMAL_BUTTON_COLOR.into(),
+                                    ..default()
+                                },
+                                RebindButton { action },
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    TextBundle {
+                                        text: Text::from_section(
+                                            bindings_label(bindings, action, false),
+                                            get_row_text_style(asset_server),
+                                        ),
+                                        ..default()
+                                    },
+                                    BindingsText { action },
+                                ));
+                            });
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: small_button_style(),
+                                    background_color: NORMAL_BUTTON_COLOR.into(),
+                                    ..default()
+                                },
+                                ClearBindingsButton { action },
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn(TextBundle {
+                                    text: Text::from_section(
+                                        "Clear",
+                                        get_row_text_style(asset_server),
+                                    ),
+                                    ..default()
+                                });
+                            });
+                    });
+            }
+            // ==== Reset / Back Buttons ====
+            parent
+                .spawn(NodeBundle {
+                    style: row_style(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style(),
+                                background_color: NORMAL_BUTTON_COLOR.into(),
+                                ..default()
+                            },
+                            ResetBindingsButton {},
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle {
+                                text: Text {
+                                    sections: vec![TextSection::new(
+                                        "Reset",
+                                        get_button_text_style(asset_server),
+                                    )],
+                                    alignment: TextAlignment::Center,
+                                    ..default()
+                                },
+                                ..default()
+                            });
+                        });
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style(),
+                                background_color: NORMAL_BUTTON_COLOR.into(),
+                                ..default()
+                            },
+                            BackButton {},
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle {
+                                text: Text {
+                                    sections: vec![TextSection::new(
+                                        "Back",
+                                        get_button_text_style(asset_server),
+                                    )],
+                                    alignment: TextAlignment::Center,
+                                    ..default()
+                                },
+                                ..default()
+                            });
+                        });
+                });
+        })
+        .id();
+
+    controls_menu_entity
+}
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+use super::resources::{Attack, BossDefinition, PartKind};
+use crate::damage::{Damage, Damageable};
+
+/// The centre of a boss, its parts are children
+#[derive(Component)]
+pub struct Boss {
+    pub definition: BossDefinition,
+    pub max_health: f32,
+    pub health_pct: f32,
+    pub phase: usize,
+    pub attack: usize,
+    pub volleys_fired: u32,
+    pub attack_timer: Timer,
+    pub spiral_angle: f32,
+}
+
+impl Boss {
+    pub fn new(definition: BossDefinition) -> Self {
+        let max_health = definition.max_health();
+        let delay = definition.phases[0].attacks[0].delay;
+        Boss {
+            definition,
+            max_health,
+            health_pct: 1.,
+            phase: 0,
+            attack: 0,
+            volleys_fired: 0,
+            attack_timer: Timer::from_seconds(delay, TimerMode::Repeating),
+            spiral_angle: 0.,
+        }
+    }
+
+    pub fn current_attack(&self) -> &Attack {
+        let attacks = &self.definition.phases[self.phase].attacks;
+        &attacks[self.attack % attacks.len()]
+    }
+
+    /// Counts a volley, moving on to the next attack once this one has fired all of its own.
+    /// Returns true when the attack changes
+    pub fn fired_volley(&mut self) -> bool {
+        self.volleys_fired += 1;
+        if self.volleys_fired < self.current_attack().volleys {
+            return false;
+        }
+        self.attack += 1;
+        self.volleys_fired = 0;
+        let delay = self.current_attack().delay;
+        self.attack_timer = Timer::from_seconds(delay, TimerMode::Repeating);
+        true
+    }
+
+    pub fn enter_phase(&mut self, phase: usize) {
+        self.phase = phase;
+        self.attack = 0;
+        self.volleys_fired = 0;
+        let delay = self.current_attack().delay;
+        self.attack_timer = Timer::from_seconds(delay, TimerMode::Repeating);
+    }
+}
+
+/// One destructible piece of a boss
+#[derive(Component)]
+pub struct BossPart {
+    pub kind: PartKind,
+    health: f32,
+}
+
+impl BossPart {
+    pub fn new(kind: PartKind, health: f32) -> Self {
+        BossPart { kind, health }
+    }
+}
+
+impl Damageable for BossPart {
+    fn damage(&mut self, entity: &impl Damage) {
+        self.health -= entity.hit_points();
+    }
+
+    fn health(&self) -> f32 {
+        self.health.max(0.)
+    }
+}
+
+impl Damage for BossPart {
+    fn hit_points(&self) -> f32 {
+        self.health * 10.
+    }
+}
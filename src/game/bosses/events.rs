@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Sent when a boss loses its cockpit
+#[derive(Event)]
+pub struct BossDestroyed {
+    pub position: Vec2,
+    pub points: u32,
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod resources;
+mod systems;
+
+use crate::game::damage::systems::apply_explosions;
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use components::BossPart;
+use events::BossDestroyed;
+use resources::BossRegistry;
+use systems::*;
+
+pub const BOSSES_FILE: &str = "bosses/bosses.json";
+pub const BOSS_EVERY_N_WAVES: u32 = 3;
+pub const BOSS_SCALE: f32 = 0.8;
+// bosses hover across the top of the screen, relative to the window height
+pub const BOSS_HOVER_HEIGHT_REL_TO_WINDOW: f32 = 0.8;
+pub const BOSS_HOVER_RANGE_REL_TO_WINDOW: (f32, f32) = (0.2, 0.8);
+pub const BOSS_ARRIVE_SLOWING_RADIUS: f32 = 120.;
+pub const BOSS_SHOT_LIFETIME: f32 = 3.;
+
+pub struct BossesPlugin;
+
+impl Plugin for BossesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(BossRegistry::build(BOSSES_FILE).unwrap())
+            .add_event::<BossDestroyed>()
+            .add_systems(
+                Update,
+                (
+                    spawn_boss_on_wave,
+                    boss_attack,
+                    (
+                        handle_shot_hits_on_boss_parts,
+                        apply_explosions::<BossPart>,
+                        handle_boss_part_destruction,
+                        update_boss_phase,
+                    )
+                        .chain(),
+                    render_boss_health,
+                )
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            )
+            .add_systems(OnExit(AppState::Game), despawn_bosses);
+    }
+}
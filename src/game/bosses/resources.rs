@@ -0,0 +1,228 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::fs;
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartKind {
+    /// Losing the cockpit destroys the whole boss
+    Cockpit,
+    Wing,
+    Engine,
+    /// Volleys are fired from every gun still standing
+    Gun,
+}
+
+impl PartKind {
+    /// Draw order within the boss, guns over the cockpit over the wings and engines
+    pub fn z(&self) -> f32 {
+        match self {
+            PartKind::Engine => 0.,
+            PartKind::Wing => 0.1,
+            PartKind::Cockpit => 0.2,
+            PartKind::Gun => 0.3,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PartDefinition {
+    pub kind: PartKind,
+    pub sprite_name: String,
+    /// Relative to the boss centre, with +y pointing the way the boss faces
+    pub offset: Vec2,
+    pub health: f32,
+    #[serde(default)]
+    pub flip_x: bool,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum AttackPattern {
+    /// A fan of shots from every gun toward the player
+    Aimed { count: usize, spread_degrees: f32 },
+    /// Shots evenly spaced all the way round the boss
+    Ring { count: usize },
+    /// A ring that turns a little further every volley
+    Spiral { count: usize, turn_degrees: f32 },
+}
+
+impl AttackPattern {
+    /// Angles in radians to fire at from an origin, given the angle toward the player
+    pub fn angles(&self, aim: f32, spiral_angle: f32) -> Vec<f32> {
+        match self {
+            AttackPattern::Aimed {
+                count,
+                spread_degrees,
+            } => {
+                if *count <= 1 {
+                    return vec![aim];
+                }
+                let spread = spread_degrees.to_radians();
+                let step = spread / (*count - 1) as f32;
+                (0..*count)
+                    .map(|i| aim - spread / 2. + step * i as f32)
+                    .collect()
+            }
+            AttackPattern::Ring { count } => ring(*count, 0.),
+            AttackPattern::Spiral { count, .. } => ring(*count, spiral_angle),
+        }
+    }
+
+    /// How far a spiral turns between volleys, in radians
+    pub fn turn(&self) -> f32 {
+        match self {
+            AttackPattern::Spiral { turn_degrees, .. } => turn_degrees.to_radians(),
+            _ => 0.,
+        }
+    }
+}
+
+fn ring(count: usize, offset: f32) -> Vec<f32> {
+    (0..count)
+        .map(|i| offset + std::f32::consts::TAU * i as f32 / count as f32)
+        .collect()
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Attack {
+    pub pattern: AttackPattern,
+    /// Volleys fired before moving on to the phase's next attack
+    pub volleys: u32,
+    #[serde(rename = "delay_secs")]
+    pub delay: f32,
+    pub sprite_name: String,
+    pub damage: f32,
+    pub speed: f32,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Phase {
+    /// The phase begins once the boss's total health falls to this fraction
+    pub health_pct: f32,
+    pub speed: f32,
+    pub attacks: Vec<Attack>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BossDefinition {
+    pub name: String,
+    pub points: u32,
+    pub parts: Vec<PartDefinition>,
+    /// In order, from full health down
+    pub phases: Vec<Phase>,
+}
+
+impl BossDefinition {
+    pub fn max_health(&self) -> f32 {
+        self.parts.iter().map(|part| part.health).sum()
+    }
+
+    /// Catches definitions the boss systems can't run, naming the first problem found
+    pub fn validate(&self) -> Result<(), String> {
+        if self.parts.is_empty() {
+            return Err(format!("{} has no parts", self.name));
+        }
+        if !self.parts.iter().any(|part| part.kind == PartKind::Cockpit) {
+            return Err(format!("{} has no cockpit", self.name));
+        }
+        if self.phases.is_empty() {
+            return Err(format!("{} has no phases", self.name));
+        }
+        for (index, phase) in self.phases.iter().enumerate() {
+            if phase.attacks.is_empty() {
+                return Err(format!("{} phase {index} has no attacks", self.name));
+            }
+            if phase.attacks.iter().any(|attack| attack.delay <= 0.) {
+                return Err(format!(
+                    "{} phase {index} has an attack without a positive delay_secs",
+                    self.name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The last phase whose threshold `health_pct` has fallen to
+    pub fn phase_index(&self, health_pct: f32) -> usize {
+        self.phases
+            .iter()
+            .rposition(|phase| health_pct <= phase.health_pct)
+            .unwrap_or(0)
+    }
+}
+
+/// Every boss in the order they turn up
+#[derive(Resource)]
+pub struct BossRegistry {
+    bosses: Vec<BossDefinition>,
+}
+
+impl BossRegistry {
+    pub fn build(bosses_file: &str) -> Result<BossRegistry, std::io::Error> {
+        let bosses_file = format!("assets/{bosses_file}");
+        let json_string = fs::read_to_string(bosses_file)?;
+        let bosses: Vec<BossDefinition> = serde_json::from_str(&json_string)?;
+        for boss in bosses.iter() {
+            boss.validate()
+                .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+        }
+        Ok(BossRegistry { bosses })
+    }
+
+    /// Wraps round to the first boss once they have all been seen
+    pub fn get(&self, index: usize) -> Option<&BossDefinition> {
+        if self.bosses.is_empty() {
+            return None;
+        }
+        self.bosses.get(index % self.bosses.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::bosses::BOSSES_FILE;
+
+    #[test]
+    fn test_boss_phases_follow_total_health() {
+        let registry = BossRegistry::build(BOSSES_FILE).unwrap();
+        let boss = registry.get(0).unwrap();
+        assert!(boss.parts.iter().any(|part| part.kind == PartKind::Cockpit));
+        assert_eq!(boss.phase_index(1.), 0);
+        let last = boss.phases.len() - 1;
+        assert_eq!(boss.phase_index(0.01), last);
+        for (index, phase) in boss.phases.iter().enumerate() {
+            assert_eq!(boss.phase_index(phase.health_pct), index);
+        }
+    }
+
+    #[test]
+    fn test_malformed_bosses_are_rejected() {
+        let registry = BossRegistry::build(BOSSES_FILE).unwrap();
+        let boss = registry.get(0).unwrap();
+        assert!(boss.validate().is_ok());
+
+        let mut no_phases = boss.clone();
+        no_phases.phases.clear();
+        assert!(no_phases.validate().is_err());
+
+        let mut no_attacks = boss.clone();
+        no_attacks.phases[0].attacks.clear();
+        assert!(no_attacks.validate().is_err());
+
+        let mut no_cockpit = boss.clone();
+        no_cockpit
+            .parts
+            .retain(|part| part.kind != PartKind::Cockpit);
+        assert!(no_cockpit.validate().is_err());
+
+        let mut no_delay = boss.clone();
+        no_delay.phases[0].attacks[0].delay = 0.;
+        assert!(no_delay.validate().is_err());
+
+        let no_parts: BossDefinition =
+            serde_json::from_str(r#"{ "name": "Husk", "points": 0, "parts": [], "phases": [] }"#)
+                .unwrap();
+        assert!(no_parts.validate().is_err());
+    }
+}
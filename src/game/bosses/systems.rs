@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_xpbd_2d::prelude::*;
+use rand::{thread_rng, Rng};
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use super::components::*;
+use super::events::BossDestroyed;
+use super::resources::{BossRegistry, PartKind};
+use super::*;
+use crate::game::damage::components::Faction;
+use crate::game::damage::resources::FriendlyFire;
+use crate::game::damage::Damageable;
+use crate::game::player::components::PlayerShip;
+use crate::game::shots::components::{Explosive, Projectile, Weapon};
+use crate::game::shots::resources::WeaponDefinition;
+use crate::game::shots::systems as shots_systems;
+use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
+use crate::game::steering::components::{Behaviour, Steering, SteeringTarget};
+use crate::game::waves::events::WaveStarted;
+use crate::game::world::systems as world_systems;
+
+pub fn despawn_bosses(mut commands: Commands, boss_query: Query<Entity, With<Boss>>) {
+    for entity in boss_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn spawn_boss_on_wave(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    boss_registry: Res<BossRegistry>,
+    mut wave_started_events: EventReader<WaveStarted>,
+    boss_query: Query<(), With<Boss>>,
+) {
+    for event in wave_started_events.read() {
+        if event.number % BOSS_EVERY_N_WAVES != 0 || !boss_query.is_empty() {
+            continue;
+        }
+        let index = (event.number / BOSS_EVERY_N_WAVES - 1) as usize;
+        let Some(definition) = boss_registry.get(index) else {
+            return;
+        };
+        let window = window_query.get_single().unwrap();
+        info!("{} approaches", definition.name);
+        let speed = definition.phases[0].speed;
+        let steering = Steering::new(speed, speed * 2.)
+            .with(
+                Behaviour::Arrive {
+                    target: SteeringTarget::Position(hover_point(window)),
+                    slowing_radius: BOSS_ARRIVE_SLOWING_RADIUS,
+                },
+                1.,
+            )
+            .with(
+                Behaviour::Wander {
+                    radius: 30.,
+                    distance: 60.,
+                    jitter: 2.,
+                },
+                0.2,
+            );
+        let parts = definition.parts.clone();
+        commands
+            .spawn((
+                SpatialBundle::from_transform(
+                    // parts are laid out facing up, bosses come in from the top facing down
+                    Transform::from_xyz(window.width() / 2., window.height() + 150., 0.)
+                        .with_rotation(Quat::from_rotation_z(PI))
+                        .with_scale(Vec3::splat(BOSS_SCALE)),
+                ),
+                Boss::new(definition.clone()),
+                steering,
+                Faction::Enemy,
+                RigidBody::Kinematic,
+                LinearVelocity::ZERO,
+            ))
+            .with_children(|parent| {
+                for part in parts.iter() {
+                    let mut bundle = world_systems::sprite_sheet_bundle(
+                        &asset_server,
+                        &mut texture_atlases,
+                        &sprite_loader,
+                        &part.sprite_name,
+                        1,
+                        1,
+                        0,
+                    );
+                    bundle.sprite.flip_x = part.flip_x;
+                    bundle.transform =
+                        Transform::from_translation(part.offset.extend(part.kind.z()));
+                    let sprite = sprite_loader.get_sprite(&part.sprite_name).unwrap();
+                    parent.spawn((
+                        bundle,
+                        BossPart::new(part.kind, part.health),
+                        Faction::Enemy.bundle(),
+                        Collider::cuboid(sprite.width, sprite.height),
+                    ));
+                }
+            });
+    }
+}
+
+fn hover_point(window: &Window) -> Vec2 {
+    let (min, max) = BOSS_HOVER_RANGE_REL_TO_WINDOW;
+    Vec2::new(
+        thread_rng().gen_range(window.width() * min..window.width() * max),
+        window.height() * BOSS_HOVER_HEIGHT_REL_TO_WINDOW,
+    )
+}
+
+/// Fires the current attack of the boss's phase from each gun it has left, or from its centre
+/// once the guns are gone
+pub fn boss_attack(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    friendly_fire: Res<FriendlyFire>,
+    mut boss_query: Query<(
+        Entity,
+        &Faction,
+        &mut Boss,
+        &mut Steering,
+        &Transform,
+        &Children,
+    )>,
+    part_query: Query<(&BossPart, &GlobalTransform)>,
+    player_query: Query<&Transform, With<PlayerShip>>,
+    time: Res<Time>,
+) {
+    let window = window_query.get_single().unwrap();
+    let player_position = player_query
+        .get_single()
+        .ok()
+        .map(|transform| transform.translation.truncate());
+    for (entity, faction, mut boss, mut steering, transform, children) in boss_query.iter_mut() {
+        if !boss.attack_timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+        let centre = transform.translation.truncate();
+        let mut origins: Vec<Vec2> = children
+            .iter()
+            .filter_map(|child| part_query.get(*child).ok())
+            .filter(|(part, _)| part.kind == PartKind::Gun && !part.is_dead())
+            .map(|(_, global_transform)| global_transform.translation().truncate())
+            .collect();
+        if origins.is_empty() {
+            origins.push(centre);
+        }
+        let attack = boss.current_attack().clone();
+        let definition = WeaponDefinition {
+            sprite_name: attack.sprite_name.clone(),
+            damage: attack.damage,
+            speed: attack.speed,
+            lifetime: BOSS_SHOT_LIFETIME,
+            ..default()
+        };
+        for origin in origins {
+            let to_player = player_position.map_or(Vec2::NEG_Y, |player| player - origin);
+            let aim = to_player.y.atan2(to_player.x);
+            for angle in attack.pattern.angles(aim, boss.spiral_angle) {
+                let direction = Vec2::from_angle(angle);
+                // shot sprites point up the y axis
+                let shot_transform = Transform::from_translation(origin.extend(0.))
+                    .with_rotation(Quat::from_rotation_z(angle - FRAC_PI_2));
+                shots_systems::spawn_weapon_at_position(
+                    &mut commands,
+                    &asset_server,
+                    &mut texture_atlases,
+                    &sprite_loader,
+                    &attack.sprite_name,
+                    Weapon::from_definition(&definition),
+                    Projectile::from_definition(&definition),
+                    (entity, *faction),
+                    &friendly_fire,
+                    shot_transform,
+                    LinearVelocity(direction * definition.speed),
+                );
+            }
+        }
+        boss.spiral_angle += attack.pattern.turn();
+        if boss.fired_volley() {
+            steering.retarget(SteeringTarget::Position(hover_point(window)));
+        }
+    }
+}
+
+pub fn handle_shot_hits_on_boss_parts(
+    shot_query: Query<(&Weapon, &ShapeHits), Without<Explosive>>,
+    mut part_query: Query<&mut BossPart>,
+) {
+    for (shot, hits) in shot_query.iter() {
+        if let Some(hit) = shots_systems::shot_hit(hits) {
+            if let Ok(mut part) = part_query.get_mut(hit.entity) {
+                part.damage(shot);
+            }
+        }
+    }
+}
+
+/// Knocks destroyed parts off their boss, and takes the whole boss down with its cockpit
+pub fn handle_boss_part_destruction(
+    mut commands: Commands,
+    part_query: Query<(Entity, &BossPart, &Parent)>,
+    boss_query: Query<(&Boss, &Transform)>,
+    mut boss_destroyed_events: EventWriter<BossDestroyed>,
+) {
+    let mut destroyed = Vec::new();
+    for (entity, part, parent) in part_query.iter() {
+        if !part.is_dead() {
+            continue;
+        }
+        if part.kind != PartKind::Cockpit {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        if destroyed.contains(&parent.get()) {
+            continue;
+        }
+        if let Ok((boss, transform)) = boss_query.get(parent.get()) {
+            info!("{} destroyed", boss.definition.name);
+            destroyed.push(parent.get());
+            commands.entity(parent.get()).despawn_recursive();
+            boss_destroyed_events.send(BossDestroyed {
+                position: transform.translation.truncate(),
+                points: boss.definition.points,
+            });
+        }
+    }
+}
+
+/// Moves bosses on to their next phase as their parts are worn down
+pub fn update_boss_phase(
+    mut boss_query: Query<(&mut Boss, &mut Steering, &Children)>,
+    part_query: Query<&BossPart>,
+) {
+    for (mut boss, mut steering, children) in boss_query.iter_mut() {
+        let health: f32 = children
+            .iter()
+            .filter_map(|child| part_query.get(*child).ok())
+            .map(|part| part.health())
+            .sum();
+        boss.health_pct = health / boss.max_health;
+        let phase = boss.definition.phase_index(boss.health_pct);
+        if phase != boss.phase {
+            info!("{} enters phase {}", boss.definition.name, phase + 1);
+            boss.enter_phase(phase);
+            steering.max_speed = boss.definition.phases[phase].speed;
+            steering.max_force = steering.max_speed * 2.;
+        }
+    }
+}
+
+pub fn render_boss_health(
+    mut gizmos: Gizmos,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    boss_query: Query<&Boss>,
+) {
+    let window = window_query.get_single().unwrap();
+    for boss in boss_query.iter() {
+        let start = Vec2::new(window.width() * 0.25, window.height() - 20.);
+        let end = start + Vec2::X * window.width() * 0.5 * boss.health_pct;
+        gizmos.line_2d(start, end, Color::ORANGE_RED);
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+pub mod resources;
+pub mod systems;
+
+use resources::*;
+use systems::*;
+
+pub const GAMEPAD_DEAD_ZONE: f32 = 0.2;
+pub const BINDINGS_CONFIG_FILE: &str = "config/bindings.json";
+pub const SETTINGS_CONFIG_FILE: &str = "config/controls.json";
+
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputBindings::load())
+            .insert_resource(ControlSettings::load())
+            .init_resource::<ActionState>()
+            .init_resource::<ActiveGamepad>()
+            .init_resource::<AimSource>()
+            .init_resource::<StickAim>()
+            .add_systems(
+                PreUpdate,
+                (
+                    handle_gamepad_connections,
+                    update_action_state,
+                    update_aim_source,
+                )
+                    .chain()
+                    .after(bevy::input::InputSystem),
+            );
+    }
+}
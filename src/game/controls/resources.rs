@@ -0,0 +1,350 @@
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use super::{BINDINGS_CONFIG_FILE, SETTINGS_CONFIG_FILE};
+use crate::game::player::PLAYER_ROTATION_SPEED;
+
+fn load_config<T: DeserializeOwned>(path: &str) -> Option<T> {
+    let json_string = fs::read_to_string(path).ok()?;
+    match serde_json::from_str::<T>(&json_string) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            warn!("Ignoring invalid config in {path}: {e}");
+            None
+        }
+    }
+}
+
+fn save_config<T: Serialize>(path: &str, config: &T) -> Result<(), std::io::Error> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(config)?)
+}
+
+/// The gamepad the ship is controlled with, the first one connected
+#[derive(Resource, Default)]
+pub struct ActiveGamepad(pub Option<Gamepad>);
+
+/// Where the ship takes its aim from, switched to whichever was used last
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AimSource {
+    #[default]
+    Mouse,
+    Stick,
+}
+
+/// Last direction the right stick was pushed outside of the dead zone
+#[derive(Resource, Default)]
+pub struct StickAim(pub Vec2);
+
+/// How the ship is turned, either facing the mouse/right stick or the classic rotate keys
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ControlMode {
+    #[default]
+    MouseAim,
+    RotateKeys,
+}
+
+impl ControlMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ControlMode::MouseAim => "Mouse Aim",
+            ControlMode::RotateKeys => "Rotate Keys",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            ControlMode::MouseAim => ControlMode::RotateKeys,
+            ControlMode::RotateKeys => ControlMode::MouseAim,
+        }
+    }
+}
+
+/// Control options saved to `SETTINGS_CONFIG_FILE`
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ControlSettings {
+    pub mode: ControlMode,
+    /// Radians per second the ship turns at in `ControlMode::RotateKeys`
+    pub rotation_speed: f32,
+}
+
+impl Default for ControlSettings {
+    fn default() -> Self {
+        ControlSettings {
+            mode: ControlMode::default(),
+            rotation_speed: PLAYER_ROTATION_SPEED,
+        }
+    }
+}
+
+impl ControlSettings {
+    pub fn load() -> Self {
+        load_config(SETTINGS_CONFIG_FILE).unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), std::io::Error> {
+        save_config(SETTINGS_CONFIG_FILE, self)
+    }
+}
+
+/// Everything the player can do, independent of the inputs it's bound to
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InputAction {
+    Thrust,
+    Reverse,
+    RotateLeft,
+    RotateRight,
+    Fire,
+    NextWeapon,
+    PreviousWeapon,
+    Hyperspace,
+    Pause,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 9] = [
+        InputAction::Thrust,
+        InputAction::Reverse,
+        InputAction::RotateLeft,
+        InputAction::RotateRight,
+        InputAction::Fire,
+        InputAction::NextWeapon,
+        InputAction::PreviousWeapon,
+        InputAction::Hyperspace,
+        InputAction::Pause,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputAction::Thrust => "Thrust",
+            InputAction::Reverse => "Reverse",
+            InputAction::RotateLeft => "Rotate Left",
+            InputAction::RotateRight => "Rotate Right",
+            InputAction::Fire => "Fire",
+            InputAction::NextWeapon => "Next Weapon",
+            InputAction::PreviousWeapon => "Previous Weapon",
+            InputAction::Hyperspace => "Hyperspace",
+            InputAction::Pause => "Pause",
+        }
+    }
+
+    fn default_bindings(&self) -> Vec<Binding> {
+        match self {
+            InputAction::Thrust => vec![Binding::Key(KeyCode::W), Binding::Key(KeyCode::Up)],
+            InputAction::Reverse => vec![Binding::Key(KeyCode::S), Binding::Key(KeyCode::Down)],
+            InputAction::RotateLeft => vec![
+                Binding::Key(KeyCode::A),
+                Binding::Key(KeyCode::Left),
+                Binding::Gamepad(GamepadButtonType::DPadLeft),
+            ],
+            InputAction::RotateRight => vec![
+                Binding::Key(KeyCode::D),
+                Binding::Key(KeyCode::Right),
+                Binding::Gamepad(GamepadButtonType::DPadRight),
+            ],
+            InputAction::Fire => vec![
+                Binding::Key(KeyCode::Space),
+                Binding::Mouse(MouseButton::Left),
+                Binding::Gamepad(GamepadButtonType::South),
+                Binding::Gamepad(GamepadButtonType::RightTrigger),
+            ],
+            InputAction::NextWeapon => vec![
+                Binding::Key(KeyCode::E),
+                Binding::Gamepad(GamepadButtonType::DPadUp),
+            ],
+            InputAction::PreviousWeapon => vec![
+                Binding::Key(KeyCode::Q),
+                Binding::Gamepad(GamepadButtonType::DPadDown),
+            ],
+            InputAction::Hyperspace => vec![
+                Binding::Key(KeyCode::ShiftLeft),
+                Binding::Key(KeyCode::H),
+                Binding::Gamepad(GamepadButtonType::North),
+            ],
+            InputAction::Pause => vec![
+                Binding::Key(KeyCode::Escape),
+                Binding::Gamepad(GamepadButtonType::Start),
+            ],
+        }
+    }
+}
+
+/// A single input that can trigger an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButtonType),
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Binding::Key(key) => write!(f, "{key:?}"),
+            Binding::Mouse(button) => write!(f, "Mouse {button:?}"),
+            Binding::Gamepad(button) => write!(f, "Pad {button:?}"),
+        }
+    }
+}
+
+/// The raw inputs an action's bindings are checked against
+pub struct InputSources<'a> {
+    pub keyboard: &'a Input<KeyCode>,
+    pub mouse: &'a Input<MouseButton>,
+    pub gamepad_buttons: &'a Input<GamepadButton>,
+    pub gamepad: Option<Gamepad>,
+}
+
+impl Binding {
+    pub fn pressed(&self, inputs: &InputSources) -> bool {
+        match self {
+            Binding::Key(key) => inputs.keyboard.pressed(*key),
+            Binding::Mouse(button) => inputs.mouse.pressed(*button),
+            Binding::Gamepad(button_type) => inputs.gamepad.is_some_and(|gamepad| {
+                inputs
+                    .gamepad_buttons
+                    .pressed(GamepadButton::new(gamepad, *button_type))
+            }),
+        }
+    }
+
+    pub fn just_pressed(&self, inputs: &InputSources) -> bool {
+        match self {
+            Binding::Key(key) => inputs.keyboard.just_pressed(*key),
+            Binding::Mouse(button) => inputs.mouse.just_pressed(*button),
+            Binding::Gamepad(button_type) => inputs.gamepad.is_some_and(|gamepad| {
+                inputs
+                    .gamepad_buttons
+                    .just_pressed(GamepadButton::new(gamepad, *button_type))
+            }),
+        }
+    }
+
+    pub fn just_released(&self, inputs: &InputSources) -> bool {
+        match self {
+            Binding::Key(key) => inputs.keyboard.just_released(*key),
+            Binding::Mouse(button) => inputs.mouse.just_released(*button),
+            Binding::Gamepad(button_type) => inputs.gamepad.is_some_and(|gamepad| {
+                inputs
+                    .gamepad_buttons
+                    .just_released(GamepadButton::new(gamepad, *button_type))
+            }),
+        }
+    }
+
+    /// The first input pressed this frame, used when capturing a new binding
+    pub fn first_just_pressed(inputs: &InputSources) -> Option<Binding> {
+        if let Some(key) = inputs.keyboard.get_just_pressed().next() {
+            return Some(Binding::Key(*key));
+        }
+        if let Some(button) = inputs.mouse.get_just_pressed().next() {
+            return Some(Binding::Mouse(*button));
+        }
+        inputs
+            .gamepad_buttons
+            .get_just_pressed()
+            .find(|button| Some(button.gamepad) == inputs.gamepad)
+            .map(|button| Binding::Gamepad(button.button_type))
+    }
+}
+
+/// The inputs bound to each action, saved to `BINDINGS_CONFIG_FILE`
+#[derive(Resource, Clone, Serialize, Deserialize)]
+pub struct InputBindings {
+    bindings: BTreeMap<InputAction, Vec<Binding>>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        InputBindings {
+            bindings: InputAction::ALL
+                .iter()
+                .map(|action| (*action, action.default_bindings()))
+                .collect(),
+        }
+    }
+}
+
+impl InputBindings {
+    /// Loads the saved bindings, falling back to the defaults for anything missing or unreadable
+    pub fn load() -> Self {
+        let mut bindings = InputBindings::default();
+        if let Some(saved) = load_config::<InputBindings>(BINDINGS_CONFIG_FILE) {
+            bindings.bindings.extend(saved.bindings);
+        }
+        bindings
+    }
+
+    pub fn save(&self) -> Result<(), std::io::Error> {
+        save_config(BINDINGS_CONFIG_FILE, self)
+    }
+
+    pub fn get(&self, action: InputAction) -> &[Binding] {
+        self.bindings
+            .get(&action)
+            .map(|bindings| bindings.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn add(&mut self, action: InputAction, binding: Binding) {
+        let bindings = self.bindings.entry(action).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    pub fn clear(&mut self, action: InputAction) {
+        self.bindings.insert(action, vec![]);
+    }
+}
+
+/// Which actions are active this frame, derived from `InputBindings` before `Update` runs
+#[derive(Resource, Default)]
+pub struct ActionState {
+    pressed: HashSet<InputAction>,
+    just_pressed: HashSet<InputAction>,
+    just_released: HashSet<InputAction>,
+}
+
+impl ActionState {
+    pub fn update(&mut self, bindings: &InputBindings, inputs: &InputSources) {
+        self.pressed.clear();
+        self.just_pressed.clear();
+        self.just_released.clear();
+        for action in InputAction::ALL {
+            let action_bindings = bindings.get(action);
+            if action_bindings.iter().any(|b| b.pressed(inputs)) {
+                self.pressed.insert(action);
+            }
+            if action_bindings.iter().any(|b| b.just_pressed(inputs)) {
+                self.just_pressed.insert(action);
+            }
+            if !self.pressed.contains(&action)
+                && action_bindings.iter().any(|b| b.just_released(inputs))
+            {
+                self.just_released.insert(action);
+            }
+        }
+    }
+
+    pub fn pressed(&self, action: InputAction) -> bool {
+        self.pressed.contains(&action)
+    }
+
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        self.just_pressed.contains(&action)
+    }
+
+    pub fn just_released(&self, action: InputAction) -> bool {
+        self.just_released.contains(&action)
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::input::gamepad::{GamepadConnection, GamepadConnectionEvent};
+use bevy::prelude::*;
+
+use super::resources::*;
+use super::GAMEPAD_DEAD_ZONE;
+
+pub fn handle_gamepad_connections(
+    mut active_gamepad: ResMut<ActiveGamepad>,
+    mut connection_events: EventReader<GamepadConnectionEvent>,
+) {
+    for event in connection_events.read() {
+        match &event.connection {
+            GamepadConnection::Connected(info) => {
+                if active_gamepad.0.is_none() {
+                    info!("Gamepad connected: {}", info.name);
+                    active_gamepad.0 = Some(event.gamepad);
+                }
+            }
+            GamepadConnection::Disconnected => {
+                if active_gamepad.0 == Some(event.gamepad) {
+                    info!("Gamepad disconnected");
+                    active_gamepad.0 = None;
+                }
+            }
+        }
+    }
+}
+
+pub fn update_action_state(
+    mut action_state: ResMut<ActionState>,
+    bindings: Res<InputBindings>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    active_gamepad: Res<ActiveGamepad>,
+) {
+    action_state.update(
+        &bindings,
+        &InputSources {
+            keyboard: &keyboard_input,
+            mouse: &mouse_input,
+            gamepad_buttons: &gamepad_buttons,
+            gamepad: active_gamepad.0,
+        },
+    );
+}
+
+pub fn update_aim_source(
+    mut aim_source: ResMut<AimSource>,
+    mut stick_aim: ResMut<StickAim>,
+    mut cursor_moved_events: EventReader<CursorMoved>,
+    active_gamepad: Res<ActiveGamepad>,
+    axes: Res<Axis<GamepadAxis>>,
+) {
+    if cursor_moved_events.read().last().is_some() && *aim_source != AimSource::Mouse {
+        *aim_source = AimSource::Mouse;
+    }
+
+    if let Some(gamepad) = active_gamepad.0 {
+        let aim = stick_position(
+            &axes,
+            gamepad,
+            GamepadAxisType::RightStickX,
+            GamepadAxisType::RightStickY,
+        );
+        if aim != Vec2::ZERO {
+            stick_aim.0 = aim;
+            if *aim_source != AimSource::Stick {
+                *aim_source = AimSource::Stick;
+            }
+        }
+    }
+}
+
+/// Position of a stick with the dead zone removed, rescaled so it ramps up from 0 at the edge
+/// of the dead zone to 1 at full tilt
+pub fn stick_position(
+    axes: &Axis<GamepadAxis>,
+    gamepad: Gamepad,
+    x_axis: GamepadAxisType,
+    y_axis: GamepadAxisType,
+) -> Vec2 {
+    let position = Vec2::new(
+        axes.get(GamepadAxis::new(gamepad, x_axis)).unwrap_or(0.),
+        axes.get(GamepadAxis::new(gamepad, y_axis)).unwrap_or(0.),
+    );
+    let length = position.length();
+    if length < GAMEPAD_DEAD_ZONE {
+        return Vec2::ZERO;
+    }
+    position / length * remove_dead_zone(length)
+}
+
+/// Analog value of a trigger with the dead zone removed
+pub fn trigger_value(
+    button_axes: &Axis<GamepadButton>,
+    gamepad: Gamepad,
+    button_type: GamepadButtonType,
+) -> f32 {
+    let value = button_axes
+        .get(GamepadButton::new(gamepad, button_type))
+        .unwrap_or(0.);
+    if value < GAMEPAD_DEAD_ZONE {
+        return 0.;
+    }
+    remove_dead_zone(value)
+}
+
+fn remove_dead_zone(value: f32) -> f32 {
+    (value.min(1.) - GAMEPAD_DEAD_ZONE) / (1. - GAMEPAD_DEAD_ZONE)
+}
+
+/// Thrust from the triggers, falling back to the left stick, in `[-1, 1]`. Negative is reverse
+pub fn gamepad_thrust(
+    axes: &Axis<GamepadAxis>,
+    button_axes: &Axis<GamepadButton>,
+    gamepad: Gamepad,
+) -> f32 {
+    let triggers = trigger_value(button_axes, gamepad, GamepadButtonType::RightTrigger2)
+        - trigger_value(button_axes, gamepad, GamepadButtonType::LeftTrigger2);
+    if triggers != 0. {
+        return triggers;
+    }
+    stick_position(
+        axes,
+        gamepad,
+        GamepadAxisType::LeftStickX,
+        GamepadAxisType::LeftStickY,
+    )
+    .y
+}
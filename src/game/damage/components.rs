@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+use bevy_xpbd_2d::prelude::*;
+use std::time::Duration;
+
+use super::resources::FriendlyFire;
+use super::{SHIELD_CAPACITY, SHIELD_RECHARGE_DELAY, SHIELD_RECHARGE_RATE};
+
+/// Which side an entity fights on. Shots carry the faction of whoever fired them
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Faction {
+    Player,
+    Enemy,
+    Neutral,
+}
+
+/// One collision layer per faction
+#[derive(Clone, Copy)]
+pub enum Layer {
+    Player = 1,
+    Enemy = 1 << 1,
+    Neutral = 1 << 2,
+}
+
+impl PhysicsLayer for Layer {
+    fn to_bits(&self) -> u32 {
+        *self as u32
+    }
+
+    fn all_bits() -> u32 {
+        0b111
+    }
+}
+
+impl Faction {
+    pub fn layer(&self) -> Layer {
+        match self {
+            Faction::Player => Layer::Player,
+            Faction::Enemy => Layer::Enemy,
+            Faction::Neutral => Layer::Neutral,
+        }
+    }
+
+    /// Bodies still bump into every faction, the layer only decides what their shots can hit
+    pub fn collision_layers(&self) -> CollisionLayers {
+        CollisionLayers::new(
+            [self.layer()],
+            [Layer::Player, Layer::Enemy, Layer::Neutral],
+        )
+    }
+
+    pub fn bundle(self) -> (Faction, CollisionLayers) {
+        (self, self.collision_layers())
+    }
+
+    /// Limits shots and beams fired by this faction to the layers they are allowed to hit
+    pub fn shot_filter(&self, friendly_fire: &FriendlyFire) -> SpatialQueryFilter {
+        let layers = [Faction::Player, Faction::Enemy, Faction::Neutral]
+            .into_iter()
+            .filter(|target| target != self || friendly_fire.allows(*self))
+            .map(|target| target.layer());
+        SpatialQueryFilter::new().with_masks(layers)
+    }
+}
+
+/// Absorbs damage before it reaches a `Damageable`, recharging after going a while without a hit
+#[derive(Component)]
+pub struct Shield {
+    pub capacity: f32,
+    pub strength: f32,
+    pub recharge_delay: Timer,
+    /// Strength regained per second once `recharge_delay` has finished
+    pub recharge_rate: f32,
+}
+
+impl Default for Shield {
+    fn default() -> Self {
+        Shield {
+            capacity: SHIELD_CAPACITY,
+            strength: SHIELD_CAPACITY,
+            recharge_delay: Timer::from_seconds(SHIELD_RECHARGE_DELAY, TimerMode::Once),
+            recharge_rate: SHIELD_RECHARGE_RATE,
+        }
+    }
+}
+
+impl Shield {
+    /// Soaks up as much of `hit_points` as the shield has left, returning the damage that gets through
+    pub fn absorb(&mut self, hit_points: f32) -> f32 {
+        let absorbed = hit_points.min(self.strength);
+        self.strength -= absorbed;
+        self.recharge_delay.reset();
+        hit_points - absorbed
+    }
+
+    pub fn recharge(&mut self, delta: Duration) {
+        if self.recharge_delay.tick(delta).finished() {
+            self.strength =
+                (self.strength + self.recharge_rate * delta.as_secs_f32()).min(self.capacity);
+        }
+    }
+
+    pub fn refill(&mut self) {
+        self.strength = self.capacity;
+    }
+
+    pub fn strength_pct(&self) -> f32 {
+        self.strength / self.capacity
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.strength > 0.
+    }
+
+    /// The bubble drawn around the shielded entity, fading as the shield weakens
+    pub fn bubble_sprite_name(&self) -> Option<&'static str> {
+        match self.strength_pct() {
+            pct if pct > 0.66 => Some("shield3.png"),
+            pct if pct > 0.33 => Some("shield2.png"),
+            pct if pct > 0. => Some("shield1.png"),
+            _ => None,
+        }
+    }
+}
+
+/// The sprite showing a `Shield`, spawned as a child of the shielded entity
+#[derive(Component)]
+pub struct ShieldBubble {
+    pub sprite_name: &'static str,
+}
+
+/// A child sprite showing damage once the parent's health drops to its level
+#[derive(Component)]
+pub struct DamageOverlay {
+    /// 1 for the lightest damage, up to the number of `DAMAGE_OVERLAY_THRESHOLDS`
+    pub level: usize,
+    /// Stays shown at heavier damage instead of giving way to the next overlay
+    pub stacks: bool,
+}
+
+impl DamageOverlay {
+    pub fn shown_at(&self, level: usize) -> bool {
+        if self.stacks {
+            level >= self.level
+        } else {
+            level == self.level
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shot_filter_ignores_own_faction() {
+        let entity = Entity::from_raw(1);
+        let friendly_fire = FriendlyFire::default();
+        let filter = Faction::Enemy.shot_filter(&friendly_fire);
+        assert!(filter.test(entity, Faction::Player.collision_layers()));
+        assert!(filter.test(entity, Faction::Neutral.collision_layers()));
+        assert!(!filter.test(entity, Faction::Enemy.collision_layers()));
+
+        let friendly_fire = FriendlyFire {
+            enemy: true,
+            ..friendly_fire
+        };
+        let filter = Faction::Enemy.shot_filter(&friendly_fire);
+        assert!(filter.test(entity, Faction::Enemy.collision_layers()));
+    }
+
+    #[test]
+    fn test_damage_overlays_follow_health() {
+        use crate::game::damage::lib::damage_level;
+
+        let crack = DamageOverlay {
+            level: 2,
+            stacks: false,
+        };
+        let scratch = DamageOverlay {
+            level: 2,
+            stacks: true,
+        };
+        assert_eq!(damage_level(1.), 0);
+        assert!(!crack.shown_at(damage_level(0.7)));
+        assert!(crack.shown_at(damage_level(0.5)));
+        assert!(!crack.shown_at(damage_level(0.1)));
+        assert!(scratch.shown_at(damage_level(0.1)));
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+
+use super::components::Faction;
+
+/// A blast that damages and knocks back every `Damageable` within `radius` of `centre`
+#[derive(Event, Clone)]
+pub struct Explosion {
+    pub centre: Vec2,
+    pub radius: f32,
+    /// Damage at the centre of the blast
+    pub damage: f32,
+    /// How sharply damage and knockback drop off towards the edge, 0 for none and 1 for linear
+    pub falloff: f32,
+    /// Knockback impulse at the centre of the blast
+    pub impulse: f32,
+    /// Whoever set it off, the blast spares their own side unless friendly fire allows it
+    pub faction: Faction,
+}
+
+impl Explosion {
+    /// Fraction of the full damage and impulse felt `distance` away from the centre
+    pub fn strength_at(&self, distance: f32) -> f32 {
+        if self.radius <= 0. || distance > self.radius {
+            return 0.;
+        }
+        (1. - distance / self.radius).powf(self.falloff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explosion_strength_falls_off_to_the_edge() {
+        let explosion = Explosion {
+            centre: Vec2::ZERO,
+            radius: 100.,
+            damage: 50.,
+            falloff: 1.,
+            impulse: 0.,
+            faction: Faction::Player,
+        };
+        assert_eq!(explosion.strength_at(0.), 1.);
+        assert_eq!(explosion.strength_at(50.), 0.5);
+        assert_eq!(explosion.strength_at(150.), 0.);
+        let flat = Explosion {
+            falloff: 0.,
+            ..explosion
+        };
+        assert_eq!(flat.strength_at(90.), 1.);
+    }
+}
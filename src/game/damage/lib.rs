@@ -1,21 +1,58 @@
-use crate::damage::{Damage, Damageable};
+use crate::damage::components::Shield;
+use crate::damage::events::Explosion;
+use crate::damage::resources::FriendlyFire;
+use crate::damage::{Damage, Damageable, HitPoints, DAMAGE_OVERLAY_THRESHOLDS};
+use bevy::ecs::query::ReadOnlyWorldQuery;
 use bevy::prelude::*;
 use bevy::prelude::{Commands, Entity, Query};
 use bevy_xpbd_2d::prelude::*;
 
-pub fn handle_collision_with_damageable<T: Damage + Component, Q: Damageable + Component>(
+/// How many of the damage overlay thresholds `health_pct` has fallen through
+pub fn damage_level(health_pct: f32) -> usize {
+    DAMAGE_OVERLAY_THRESHOLDS
+        .iter()
+        .filter(|threshold| health_pct <= **threshold)
+        .count()
+}
+
+/// Damages `damageable`, letting its shield soak up the hit first if it has one
+pub fn apply_damage<Q: Damageable>(
+    damageable: &mut Q,
+    shield: Option<Mut<Shield>>,
+    damage: &impl Damage,
+) {
+    match shield {
+        Some(mut shield) if shield.is_up() => {
+            let remaining = shield.absorb(damage.hit_points());
+            if remaining > 0. {
+                damageable.damage(&HitPoints(remaining));
+            }
+        }
+        _ => damageable.damage(damage),
+    }
+}
+
+pub fn handle_collision_with_damageable<
+    T: Damage + Component,
+    Q: Damageable + Component,
+    F: ReadOnlyWorldQuery,
+>(
     commands: &mut Commands,
     damage_query: &Query<&T>,
-    damageable_query: &mut Query<(Entity, &mut Q, &CollidingEntities)>,
+    damageable_query: &mut Query<(Entity, &mut Q, &CollidingEntities, Option<&mut Shield>), F>,
 ) {
-    if let Ok((damageable_entity, mut damageable, colliding_entities)) =
+    if let Ok((damageable_entity, mut damageable, colliding_entities, mut shield)) =
         damageable_query.get_single_mut()
     {
         for other_entity in colliding_entities.iter() {
             if let Ok(damage_inst) = damage_query.get(*other_entity) {
-                damageable.damage(damage_inst);
+                apply_damage(
+                    &mut *damageable,
+                    shield.as_mut().map(|s| s.reborrow()),
+                    damage_inst,
+                );
                 if damageable.is_dead() {
-                    commands.entity(damageable_entity).despawn();
+                    commands.entity(damageable_entity).despawn_recursive();
                 }
             }
         }
@@ -25,16 +62,68 @@ pub fn handle_collision_with_damageable<T: Damage + Component, Q: Damageable + C
 pub fn handle_collision_with_damage<T: Damage + Component, Q: Damageable + Component>(
     commands: &mut Commands,
     damage_query: &Query<(&T, &CollidingEntities)>,
-    damageable_query: &mut Query<(&mut Q)>,
+    damageable_query: &mut Query<(&mut Q, Option<&mut Shield>)>,
 ) {
     for (damage, colliding_entities) in damage_query.iter() {
         for other_entity in colliding_entities.iter() {
-            if let Ok(mut damageable) = damageable_query.get_mut(*other_entity) {
-                damageable.damage(damage);
+            if let Ok((mut damageable, shield)) = damageable_query.get_mut(*other_entity) {
+                apply_damage(&mut *damageable, shield, damage);
                 if damageable.is_dead() {
-                    commands.entity(*other_entity).despawn();
+                    commands.entity(*other_entity).despawn_recursive();
                 }
             }
         }
     }
 }
+
+/// Damages and knocks back every `Q` caught in `explosion`, scaled by how close it is to the
+/// centre. The blast follows the same faction rules as shots. Returns the entities that were
+/// hit, leaving whatever happens to the dead up to the caller
+pub fn apply_explosion<Q: Damageable + Component, F: ReadOnlyWorldQuery>(
+    explosion: &Explosion,
+    friendly_fire: &FriendlyFire,
+    spatial_query: &SpatialQuery,
+    damageable_query: &mut Query<
+        (
+            &mut Q,
+            &GlobalTransform,
+            Option<&mut Shield>,
+            Option<&mut LinearVelocity>,
+            Option<&Mass>,
+        ),
+        F,
+    >,
+) -> Vec<Entity> {
+    let caught = spatial_query.shape_intersections(
+        &Collider::ball(explosion.radius),
+        explosion.centre,
+        0.,
+        explosion.faction.shot_filter(friendly_fire),
+    );
+    let mut hit = Vec::new();
+    for entity in caught {
+        let Ok((mut damageable, transform, shield, linear_velocity, mass)) =
+            damageable_query.get_mut(entity)
+        else {
+            continue;
+        };
+        let offset = transform.translation().truncate() - explosion.centre;
+        let strength = explosion.strength_at(offset.length());
+        if strength <= 0. {
+            continue;
+        }
+        apply_damage(
+            &mut *damageable,
+            shield,
+            &HitPoints(explosion.damage * strength),
+        );
+        if let (Some(mut linear_velocity), Some(mass)) = (linear_velocity, mass) {
+            if mass.0 > 0. {
+                linear_velocity.0 +=
+                    offset.normalize_or_zero() * explosion.impulse * strength / mass.0;
+            }
+        }
+        hit.push(entity);
+    }
+    hit
+}
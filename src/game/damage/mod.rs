@@ -1,4 +1,26 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
 pub mod lib;
+pub mod resources;
+pub mod systems;
+
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use events::Explosion;
+use resources::FriendlyFire;
+use systems::*;
+
+pub const SHIELD_CAPACITY: f32 = 2500.;
+pub const SHIELD_RECHARGE_DELAY: f32 = 3.0;
+pub const SHIELD_RECHARGE_RATE: f32 = 500.;
+pub const SHIELD_BUBBLE_Z: f32 = 0.1;
+pub const FRIENDLY_FIRE_PLAYER: bool = false;
+pub const FRIENDLY_FIRE_ENEMY: bool = false;
+// health left at which each further damage overlay shows
+pub const DAMAGE_OVERLAY_THRESHOLDS: [f32; 3] = [0.75, 0.5, 0.25];
+pub const SCRATCH_SPRITES: [&str; 3] = ["scratch1.png", "scratch2.png", "scratch3.png"];
 
 pub trait Damage {
     fn hit_points(&self) -> f32;
@@ -12,3 +34,32 @@ pub trait Damageable {
         self.health() <= 0f32
     }
 }
+
+/// Damageables that know how much of their health is left, as a fraction of full health
+pub trait HealthPct {
+    fn health_pct(&self) -> f32;
+}
+
+/// A fixed amount of damage, e.g. whatever gets past a shield
+pub struct HitPoints(pub f32);
+
+impl Damage for HitPoints {
+    fn hit_points(&self) -> f32 {
+        self.0
+    }
+}
+
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Explosion>()
+            .init_resource::<FriendlyFire>()
+            .add_systems(
+                Update,
+                (recharge_shields, update_shield_bubbles)
+                    .chain()
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            );
+    }
+}
@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+use super::components::Faction;
+use super::{FRIENDLY_FIRE_ENEMY, FRIENDLY_FIRE_PLAYER};
+
+/// Whether shots can hurt other members of the faction that fired them
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct FriendlyFire {
+    pub player: bool,
+    pub enemy: bool,
+}
+
+impl Default for FriendlyFire {
+    fn default() -> Self {
+        FriendlyFire {
+            player: FRIENDLY_FIRE_PLAYER,
+            enemy: FRIENDLY_FIRE_ENEMY,
+        }
+    }
+}
+
+impl FriendlyFire {
+    pub fn allows(&self, faction: Faction) -> bool {
+        match faction {
+            Faction::Player => self.player,
+            Faction::Enemy => self.enemy,
+            // nobody is on the same side as a neutral
+            Faction::Neutral => true,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use bevy_xpbd_2d::prelude::*;
+
+use super::components::*;
+use super::events::Explosion;
+use super::lib as damage_lib;
+use super::resources::FriendlyFire;
+use super::SHIELD_BUBBLE_Z;
+use super::{Damageable, HealthPct};
+use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
+use crate::game::world::systems as world_systems;
+
+/// Shows the `DamageOverlay` children of each `T` for how badly it's damaged
+pub fn update_damage_overlays<T: HealthPct + Component>(
+    damaged_query: Query<(&T, &Children), Changed<T>>,
+    mut overlay_query: Query<(&DamageOverlay, &mut Visibility)>,
+) {
+    for (damaged, children) in damaged_query.iter() {
+        let level = damage_lib::damage_level(damaged.health_pct());
+        for child in children.iter() {
+            if let Ok((overlay, mut visibility)) = overlay_query.get_mut(*child) {
+                *visibility = if overlay.shown_at(level) {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+            }
+        }
+    }
+}
+
+pub fn recharge_shields(mut shield_query: Query<&mut Shield>, time: Res<Time>) {
+    for mut shield in shield_query.iter_mut() {
+        shield.recharge(time.delta());
+    }
+}
+
+pub fn update_shield_bubbles(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    shield_query: Query<(Entity, &Shield, Option<&Children>)>,
+    bubble_query: Query<&ShieldBubble>,
+) {
+    for (entity, shield, children) in shield_query.iter() {
+        let sprite_name = shield.bubble_sprite_name();
+        let mut has_bubble = false;
+        for child in children.into_iter().flatten() {
+            if let Ok(bubble) = bubble_query.get(*child) {
+                if Some(bubble.sprite_name) == sprite_name {
+                    has_bubble = true;
+                } else {
+                    commands.entity(*child).despawn_recursive();
+                }
+            }
+        }
+
+        if let (Some(sprite_name), false) = (sprite_name, has_bubble) {
+            let bubble = commands
+                .spawn((
+                    world_systems::sprite_sheet_bundle(
+                        &asset_server,
+                        &mut texture_atlases,
+                        &sprite_loader,
+                        sprite_name,
+                        1,
+                        1,
+                        0,
+                    ),
+                    ShieldBubble { sprite_name },
+                ))
+                .insert(Transform::from_xyz(0., 0., SHIELD_BUBBLE_Z))
+                .id();
+            commands.entity(entity).add_child(bubble);
+        }
+    }
+}
+
+/// Registered by each module for its own `Damageable` so explosions reach it
+pub fn apply_explosions<Q: Damageable + Component>(
+    mut explosion_events: EventReader<Explosion>,
+    friendly_fire: Res<FriendlyFire>,
+    spatial_query: SpatialQuery,
+    mut damageable_query: Query<(
+        &mut Q,
+        &GlobalTransform,
+        Option<&mut Shield>,
+        Option<&mut LinearVelocity>,
+        Option<&Mass>,
+    )>,
+) {
+    for explosion in explosion_events.read() {
+        damage_lib::apply_explosion(
+            explosion,
+            &friendly_fire,
+            &spatial_query,
+            &mut damageable_query,
+        );
+    }
+}
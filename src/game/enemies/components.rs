@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use super::{
+    ENEMY_ARRIVE_SLOWING_RADIUS, ENEMY_AVOIDANCE_LOOK_AHEAD, ENEMY_DIRECTION_CHANGE_TIME,
+    ENEMY_EVADE_DISTANCE,
+};
+use crate::damage::{Damage, Damageable};
+use crate::game::steering::components::{Behaviour, Steering, SteeringTarget};
+
+/// The classic flying saucers, big ones are slow and fire anywhere, small ones are quick and aim
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaucerKind {
+    Big,
+    Small,
+}
+
+impl SaucerKind {
+    pub fn sprite_name(&self) -> &'static str {
+        match self {
+            SaucerKind::Big => "ufoGreen.png",
+            SaucerKind::Small => "ufoRed.png",
+        }
+    }
+
+    pub fn scale(&self) -> f32 {
+        match self {
+            SaucerKind::Big => 0.7,
+            SaucerKind::Small => 0.4,
+        }
+    }
+
+    pub fn speed(&self) -> f32 {
+        match self {
+            SaucerKind::Big => 90.,
+            SaucerKind::Small => 150.,
+        }
+    }
+
+    pub fn health(&self) -> f32 {
+        match self {
+            SaucerKind::Big => 100.,
+            SaucerKind::Small => 40.,
+        }
+    }
+
+    pub fn fire_delay(&self) -> f32 {
+        match self {
+            SaucerKind::Big => 1.5,
+            SaucerKind::Small => 1.0,
+        }
+    }
+
+    pub fn shot_damage(&self) -> f32 {
+        match self {
+            SaucerKind::Big => 150.,
+            SaucerKind::Small => 200.,
+        }
+    }
+
+    /// Big saucers meander and shy away from the player, small ones dart between waypoints
+    /// and hound the player without getting too close
+    pub fn steering(&self, waypoint: Vec2) -> Steering {
+        let waypoint = SteeringTarget::Position(waypoint);
+        let steering = Steering::new(self.speed(), self.speed() * 2.).with(
+            Behaviour::AvoidObstacles {
+                look_ahead: ENEMY_AVOIDANCE_LOOK_AHEAD,
+            },
+            2.,
+        );
+        match self {
+            SaucerKind::Big => steering
+                .with(Behaviour::Seek(waypoint), 1.)
+                .with(
+                    Behaviour::Wander {
+                        radius: 40.,
+                        distance: 80.,
+                        jitter: 3.,
+                    },
+                    0.3,
+                )
+                .with(
+                    Behaviour::Flee {
+                        threat: SteeringTarget::Player,
+                        panic_distance: ENEMY_EVADE_DISTANCE,
+                    },
+                    0.5,
+                ),
+            SaucerKind::Small => steering
+                .with(
+                    Behaviour::Arrive {
+                        target: waypoint,
+                        slowing_radius: ENEMY_ARRIVE_SLOWING_RADIUS,
+                    },
+                    1.,
+                )
+                .with(Behaviour::Pursue(SteeringTarget::Player), 0.3)
+                .with(
+                    Behaviour::Evade {
+                        threat: SteeringTarget::Player,
+                        panic_distance: ENEMY_EVADE_DISTANCE,
+                    },
+                    1.,
+                ),
+        }
+    }
+
+    pub fn points(&self) -> u32 {
+        match self {
+            SaucerKind::Big => 200,
+            SaucerKind::Small => 1000,
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct Enemy {
+    pub kind: SaucerKind,
+    /// 1 when crossing left to right, -1 when crossing right to left
+    pub heading: f32,
+    health: f32,
+    pub fire_timer: Timer,
+    pub direction_timer: Timer,
+}
+
+impl Enemy {
+    pub fn new(kind: SaucerKind, heading: f32) -> Self {
+        Enemy {
+            kind,
+            heading,
+            health: kind.health(),
+            fire_timer: Timer::from_seconds(kind.fire_delay(), TimerMode::Repeating),
+            direction_timer: Timer::from_seconds(ENEMY_DIRECTION_CHANGE_TIME, TimerMode::Repeating),
+        }
+    }
+
+    pub fn health_pct(&self) -> f32 {
+        self.health / self.kind.health()
+    }
+}
+
+impl Damageable for Enemy {
+    fn damage(&mut self, entity: &impl Damage) {
+        self.health -= entity.hit_points();
+    }
+
+    fn health(&self) -> f32 {
+        self.health
+    }
+}
+
+impl Damage for Enemy {
+    fn hit_points(&self) -> f32 {
+        self.kind.health() * 10.
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+use super::components::SaucerKind;
+
+/// Sent when a saucer is shot down
+#[derive(Event)]
+pub struct EnemyDestroyed {
+    pub position: Vec2,
+    pub kind: SaucerKind,
+}
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+mod resources;
+mod systems;
+
+use crate::game::damage::systems::apply_explosions;
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use components::Enemy;
+use events::EnemyDestroyed;
+use resources::EnemySpawnTimer;
+use systems::*;
+
+pub const ENEMY_SPAWN_TIME: f32 = 20.0;
+pub const ENEMY_MIN_SPAWN_TIME: f32 = 6.0;
+// seconds taken off the spawn time for every second played
+pub const ENEMY_SPAWN_TIME_DECAY: f32 = 0.05;
+pub const ENEMY_MAX_ALIVE: usize = 2;
+// saucers enter somewhere between 10% and 90% of the way up the screen
+pub const ENEMY_SPAWN_RANGE_REL_TO_WINDOW: (f32, f32) = (0.1, 0.9);
+pub const ENEMY_DIRECTION_CHANGE_TIME: f32 = 1.5;
+// how far ahead along its path a saucer picks its next waypoint, relative to the window width
+pub const ENEMY_WAYPOINT_STEP_REL_TO_WINDOW: f32 = 0.3;
+pub const ENEMY_AVOIDANCE_LOOK_AHEAD: f32 = 150.;
+pub const ENEMY_EVADE_DISTANCE: f32 = 150.;
+pub const ENEMY_ARRIVE_SLOWING_RADIUS: f32 = 80.;
+
+pub const SMALL_SAUCER_BASE_CHANCE: f32 = 0.2;
+pub const SMALL_SAUCER_MAX_CHANCE: f32 = 0.8;
+pub const SMALL_SAUCER_CHANCE_PER_SECOND: f32 = 0.002;
+// small saucers start out missing by up to this many radians and get better the longer the game goes
+pub const SMALL_SAUCER_BASE_AIM_ERROR: f32 = 0.5;
+pub const SMALL_SAUCER_MIN_AIM_ERROR: f32 = 0.05;
+pub const SMALL_SAUCER_AIM_IMPROVEMENT_PER_SECOND: f32 = 0.003;
+
+pub const ENEMY_SHOT_SPRITE: &str = "laserRed03.png";
+pub const ENEMY_SHOT_SPEED: f32 = 600.;
+pub const ENEMY_SHOT_LIFETIME: f32 = 1.5;
+
+pub struct EnemiesPlugin;
+
+impl Plugin for EnemiesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnemySpawnTimer>()
+            .add_event::<EnemyDestroyed>()
+            .add_systems(OnEnter(AppState::Game), reset_enemy_spawn_timer)
+            .add_systems(
+                Update,
+                (
+                    tick_enemy_spawn_timer,
+                    spawn_enemies_over_time,
+                    steer_enemies,
+                    enemies_fire_at_player,
+                    (
+                        handle_shot_hits_on_enemies,
+                        handle_enemy_collision_with_meteor,
+                        apply_explosions::<Enemy>,
+                        handle_enemy_destruction,
+                    )
+                        .chain(),
+                    despawn_enemies_off_screen,
+                    render_enemy_health,
+                )
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            )
+            .add_systems(OnExit(AppState::Game), despawn_enemies);
+    }
+}
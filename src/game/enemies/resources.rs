@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use bevy::time::Stopwatch;
+
+use super::*;
+
+/// Spawns saucers more and more often, and makes them more dangerous, the longer the game goes
+#[derive(Resource)]
+pub struct EnemySpawnTimer {
+    pub timer: Timer,
+    pub elapsed: Stopwatch,
+}
+
+impl Default for EnemySpawnTimer {
+    fn default() -> EnemySpawnTimer {
+        EnemySpawnTimer {
+            timer: Timer::from_seconds(ENEMY_SPAWN_TIME, TimerMode::Repeating),
+            elapsed: Stopwatch::new(),
+        }
+    }
+}
+
+impl EnemySpawnTimer {
+    pub fn spawn_time(&self) -> f32 {
+        (ENEMY_SPAWN_TIME - self.elapsed.elapsed_secs() * ENEMY_SPAWN_TIME_DECAY)
+            .max(ENEMY_MIN_SPAWN_TIME)
+    }
+
+    pub fn small_saucer_chance(&self) -> f32 {
+        (SMALL_SAUCER_BASE_CHANCE + self.elapsed.elapsed_secs() * SMALL_SAUCER_CHANCE_PER_SECOND)
+            .min(SMALL_SAUCER_MAX_CHANCE)
+    }
+
+    /// Largest angle in radians a small saucer's shot can miss the player by
+    pub fn aim_error(&self) -> f32 {
+        (SMALL_SAUCER_BASE_AIM_ERROR
+            - self.elapsed.elapsed_secs() * SMALL_SAUCER_AIM_IMPROVEMENT_PER_SECOND)
+            .max(SMALL_SAUCER_MIN_AIM_ERROR)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_enemies_get_harder_over_time() {
+        let mut enemy_spawn_timer = EnemySpawnTimer::default();
+        assert_eq!(enemy_spawn_timer.spawn_time(), ENEMY_SPAWN_TIME);
+        assert_eq!(enemy_spawn_timer.aim_error(), SMALL_SAUCER_BASE_AIM_ERROR);
+        enemy_spawn_timer.elapsed.tick(Duration::from_secs(3600));
+        assert_eq!(enemy_spawn_timer.spawn_time(), ENEMY_MIN_SPAWN_TIME);
+        assert_eq!(enemy_spawn_timer.aim_error(), SMALL_SAUCER_MIN_AIM_ERROR);
+        assert_eq!(
+            enemy_spawn_timer.small_saucer_chance(),
+            SMALL_SAUCER_MAX_CHANCE
+        );
+    }
+}
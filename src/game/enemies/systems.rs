@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_xpbd_2d::prelude::*;
+use rand::{random, thread_rng, Rng};
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use super::components::*;
+use super::events::EnemyDestroyed;
+use super::resources::EnemySpawnTimer;
+use super::*;
+use crate::game::damage::components::{Faction, Shield};
+use crate::game::damage::lib as damage_lib;
+use crate::game::damage::resources::FriendlyFire;
+use crate::game::damage::Damageable;
+use crate::game::meteors::components::Meteor;
+use crate::game::player::components::PlayerShip;
+use crate::game::shots::components::{Explosive, Projectile, Weapon};
+use crate::game::shots::resources::WeaponDefinition;
+use crate::game::shots::systems as shots_systems;
+use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
+use crate::game::steering::components::{Steering, SteeringTarget};
+use crate::game::world::systems as world_systems;
+
+pub fn despawn_enemies(mut commands: Commands, enemy_query: Query<Entity, With<Enemy>>) {
+    for entity in enemy_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn reset_enemy_spawn_timer(mut enemy_spawn_timer: ResMut<EnemySpawnTimer>) {
+    *enemy_spawn_timer = EnemySpawnTimer::default();
+}
+
+pub fn tick_enemy_spawn_timer(mut enemy_spawn_timer: ResMut<EnemySpawnTimer>, time: Res<Time>) {
+    enemy_spawn_timer.elapsed.tick(time.delta());
+    enemy_spawn_timer.timer.tick(time.delta());
+}
+
+pub fn spawn_enemies_over_time(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    mut enemy_spawn_timer: ResMut<EnemySpawnTimer>,
+    enemy_query: Query<(), With<Enemy>>,
+) {
+    if !enemy_spawn_timer.timer.just_finished() {
+        return;
+    }
+    let spawn_time = enemy_spawn_timer.spawn_time();
+    enemy_spawn_timer
+        .timer
+        .set_duration(std::time::Duration::from_secs_f32(spawn_time));
+    if enemy_query.iter().count() >= ENEMY_MAX_ALIVE {
+        return;
+    }
+    let window = window_query.get_single().unwrap();
+    let kind = if random::<f32>() < enemy_spawn_timer.small_saucer_chance() {
+        SaucerKind::Small
+    } else {
+        SaucerKind::Big
+    };
+    let mut rng = thread_rng();
+    let (min, max) = ENEMY_SPAWN_RANGE_REL_TO_WINDOW;
+    let y = rng.gen_range(window.height() * min..window.height() * max);
+    // enter from either side and head for the other
+    let (x, heading) = if random::<bool>() {
+        (0., 1.)
+    } else {
+        (window.width(), -1.)
+    };
+    let sprite_name = kind.sprite_name();
+    let radius = sprite_loader.get_sprite(sprite_name).unwrap().half_width();
+    commands
+        .spawn((
+            world_systems::sprite_sheet_bundle(
+                &asset_server,
+                &mut texture_atlases,
+                &sprite_loader,
+                sprite_name,
+                1,
+                1,
+                0,
+            ),
+            Enemy::new(kind, heading),
+            kind.steering(next_waypoint(window, x, heading)),
+            Faction::Enemy.bundle(),
+        ))
+        .insert(Transform::from_xyz(x, y, 0.).with_scale(Vec3::splat(kind.scale())))
+        .insert((
+            RigidBody::Kinematic,
+            Collider::ball(radius),
+            LinearVelocity(Vec2::new(heading * kind.speed(), 0.)),
+        ));
+}
+
+/// Saucers keep crossing the screen but every so often pick a new waypoint further along
+pub fn steer_enemies(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut enemy_query: Query<(&mut Enemy, &mut Steering, &Transform)>,
+    time: Res<Time>,
+) {
+    let window = window_query.get_single().unwrap();
+    for (mut enemy, mut steering, transform) in enemy_query.iter_mut() {
+        if enemy.direction_timer.tick(time.delta()).just_finished() {
+            let waypoint = next_waypoint(window, transform.translation.x, enemy.heading);
+            steering.retarget(SteeringTarget::Position(waypoint));
+        }
+    }
+}
+
+fn next_waypoint(window: &Window, x: f32, heading: f32) -> Vec2 {
+    let (min, max) = ENEMY_SPAWN_RANGE_REL_TO_WINDOW;
+    let y = thread_rng().gen_range(window.height() * min..window.height() * max);
+    Vec2::new(
+        x + heading * window.width() * ENEMY_WAYPOINT_STEP_REL_TO_WINDOW,
+        y,
+    )
+}
+
+pub fn despawn_enemies_off_screen(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    enemy_query: Query<(Entity, &Enemy, &Transform)>,
+) {
+    let window = window_query.get_single().unwrap();
+    for (entity, enemy, transform) in enemy_query.iter() {
+        let x = transform.translation.x;
+        if (enemy.heading < 0. && x < 0.) || (enemy.heading > 0. && x > window.width()) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+pub fn enemies_fire_at_player(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    enemy_spawn_timer: Res<EnemySpawnTimer>,
+    friendly_fire: Res<FriendlyFire>,
+    mut enemy_query: Query<(Entity, &Faction, &mut Enemy, &Transform)>,
+    player_query: Query<&Transform, With<PlayerShip>>,
+    time: Res<Time>,
+) {
+    let player_position = player_query
+        .get_single()
+        .ok()
+        .map(|transform| transform.translation.truncate());
+    for (entity, faction, mut enemy, transform) in enemy_query.iter_mut() {
+        if !enemy.fire_timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+        let position = transform.translation.truncate();
+        let mut rng = thread_rng();
+        let angle = match (enemy.kind, player_position) {
+            (SaucerKind::Small, Some(player_position)) => {
+                let to_player = player_position - position;
+                let aim_error = enemy_spawn_timer.aim_error();
+                to_player.y.atan2(to_player.x) + rng.gen_range(-aim_error..=aim_error)
+            }
+            _ => rng.gen_range(0.0..TAU),
+        };
+        let direction = Vec2::from_angle(angle);
+        let definition = WeaponDefinition {
+            sprite_name: ENEMY_SHOT_SPRITE.to_string(),
+            damage: enemy.kind.shot_damage(),
+            speed: ENEMY_SHOT_SPEED,
+            lifetime: ENEMY_SHOT_LIFETIME,
+            ..default()
+        };
+        // shot sprites point up the y axis
+        let shot_transform = Transform::from_translation(transform.translation)
+            .with_rotation(Quat::from_rotation_z(angle - FRAC_PI_2));
+        shots_systems::spawn_weapon_at_position(
+            &mut commands,
+            &asset_server,
+            &mut texture_atlases,
+            &sprite_loader,
+            ENEMY_SHOT_SPRITE,
+            Weapon::from_definition(&definition),
+            Projectile::from_definition(&definition),
+            (entity, *faction),
+            &friendly_fire,
+            shot_transform,
+            LinearVelocity(direction * definition.speed),
+        );
+    }
+}
+
+pub fn handle_shot_hits_on_enemies(
+    shot_query: Query<(&Weapon, &ShapeHits), Without<Explosive>>,
+    mut enemy_query: Query<&mut Enemy>,
+) {
+    for (shot, hits) in shot_query.iter() {
+        if let Some(hit) = shots_systems::shot_hit(hits) {
+            if let Ok(mut enemy) = enemy_query.get_mut(hit.entity) {
+                enemy.damage(shot);
+            }
+        }
+    }
+}
+
+pub fn handle_enemy_collision_with_meteor(
+    mut enemy_query: Query<(&mut Enemy, &CollidingEntities, Option<&mut Shield>)>,
+    meteor_query: Query<&Meteor>,
+) {
+    for (mut enemy, colliding_entities, shield) in enemy_query.iter_mut() {
+        if let Some(meteor) = colliding_entities
+            .iter()
+            .find_map(|entity| meteor_query.get(*entity).ok())
+        {
+            damage_lib::apply_damage(&mut *enemy, shield, meteor);
+        }
+    }
+}
+
+pub fn handle_enemy_destruction(
+    mut commands: Commands,
+    enemy_query: Query<(Entity, &Enemy, &Transform)>,
+    mut enemy_destroyed_events: EventWriter<EnemyDestroyed>,
+) {
+    for (entity, enemy, transform) in enemy_query.iter() {
+        if enemy.is_dead() {
+            commands.entity(entity).despawn_recursive();
+            enemy_destroyed_events.send(EnemyDestroyed {
+                position: transform.translation.truncate(),
+                kind: enemy.kind,
+            });
+        }
+    }
+}
+
+pub fn render_enemy_health(mut gizmos: Gizmos, enemy_query: Query<(&Enemy, &Transform)>) {
+    for (enemy, transform) in enemy_query.iter() {
+        if !enemy.is_dead() {
+            let offset = 25f32;
+            let start = Vec2::new(
+                transform.translation.x - offset,
+                transform.translation.y - offset,
+            );
+            let end = start + Vec2::X * 2. * offset * enemy.health_pct();
+            gizmos.line_2d(start, end, Color::ORANGE_RED);
+        }
+    }
+}
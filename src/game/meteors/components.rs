@@ -1,4 +1,4 @@
-use crate::damage::{Damage, Damageable};
+use crate::damage::{Damage, Damageable, HealthPct};
 use bevy::prelude::*;
 use bevy_xpbd_2d::prelude::*;
 use rand::distributions::{Uniform, WeightedIndex};
@@ -63,6 +63,14 @@ impl MeteorType {
         }
     }
 
+    pub fn points(&self) -> u32 {
+        match self {
+            MeteorType::Big => 20,
+            MeteorType::Med => 50,
+            MeteorType::Small => 100,
+        }
+    }
+
     pub fn next_size(&self) -> Self {
         let mut rng = thread_rng();
 
@@ -140,6 +148,10 @@ impl Meteor {
         vec
     }
 
+    pub fn meteor_type(&self) -> MeteorType {
+        self.meteor_type
+    }
+
     pub fn health_color(&self) -> Color {
         match self.health_pct() {
             val if val > 0.75 => Color::LIME_GREEN,
@@ -168,6 +180,12 @@ impl Damageable for Meteor {
     }
 }
 
+impl HealthPct for Meteor {
+    fn health_pct(&self) -> f32 {
+        Meteor::health_pct(self)
+    }
+}
+
 impl Damage for Meteor {
     fn hit_points(&self) -> f32 {
         self.damage
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+use super::components::MeteorType;
+
+/// Sent when a meteor is shot to pieces
+#[derive(Event)]
+pub struct MeteorDestroyed {
+    pub position: Vec2,
+    pub meteor_type: MeteorType,
+}
@@ -1,13 +1,17 @@
 use bevy::prelude::*;
 
 pub mod components;
+pub mod events;
 mod resources;
 mod systems;
 
 use crate::game::states::SimulationState;
 use crate::states::AppState;
 
+use crate::game::damage::systems::{apply_explosions, update_damage_overlays};
 use crate::game::systems::resume_simulation;
+use components::Meteor;
+use events::MeteorDestroyed;
 use resources::MeteorSpawnTimer;
 use systems::*;
 
@@ -20,6 +24,9 @@ pub const NUM_METEORS_TO_SPAWN_ON_DESTRUCTION: u32 = 3;
 pub const METEOR_SPAWN_TIME: f32 = 8.0;
 pub const CHANCE_TO_SPAWN_METEOR_ON_DESTRUCTION: f32 = 0.3;
 
+// scratches are drawn bigger than the sheet so they still show on shrunken meteors
+pub const METEOR_SCRATCH_SCALE: f32 = 1.5;
+
 // allows meteors to spawn in the first 5% of the screen or the last 5% of the screen
 pub const METEOR_SPAWN_RANGE_REL_TO_WINDOW: (f32, f32) = (0.05, 0.95);
 
@@ -34,6 +41,7 @@ pub struct MeteorPlugin;
 impl Plugin for MeteorPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MeteorSpawnTimer>()
+            .add_event::<MeteorDestroyed>()
             .configure_sets(
                 Update,
                 MeteorSystemSet::Movement.before(MeteorSystemSet::Confinement),
@@ -42,11 +50,18 @@ impl Plugin for MeteorPlugin {
             .add_systems(
                 Update,
                 (
-                    handle_weapon_collision,
+                    (
+                        handle_weapon_collision,
+                        apply_explosions::<Meteor>,
+                        handle_meteor_destruction,
+                    )
+                        .chain(),
                     constrain_meteor_velocity,
                     tick_meteor_spawn_timer,
                     spawn_meteors_over_time,
                     render_meteor_health,
+                    scratch_new_meteors,
+                    update_damage_overlays::<Meteor>,
                 )
                     .in_set(MeteorSystemSet::Movement)
                     .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
@@ -5,9 +5,13 @@ use rand::{random, Rng};
 use std::f32::consts::PI;
 
 use super::components::*;
+use super::events::MeteorDestroyed;
 use super::*;
-use crate::game::damage::Damageable;
-use crate::game::shots::components::Weapon;
+use crate::game::damage::components::{DamageOverlay, Faction};
+use crate::game::damage::lib as damage_lib;
+use crate::game::damage::{Damageable, SCRATCH_SPRITES};
+use crate::game::shots::components::{Explosive, Weapon};
+use crate::game::shots::systems as shots_systems;
 use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
 use crate::game::world;
 use crate::game::world::components::{BottomWall, LeftWall, RightWall, TopWall};
@@ -121,7 +125,7 @@ fn spawn_meteor_at_position(
             .with_velocity(velocity)
             .with_density(density),
         Transform::from_xyz(translation.x, translation.y, 0.0),
-        None::<SpriteBundle>,
+        Some(Faction::Neutral.bundle()),
     );
 }
 
@@ -211,6 +215,51 @@ fn create_new_meteors_after_destruction(
     res
 }
 
+/// Gives new meteors their scratches, shown as they're worn down
+pub fn scratch_new_meteors(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    meteor_query: Query<(Entity, &Meteor), Added<Meteor>>,
+) {
+    let mut rng = rand::thread_rng();
+    for (entity, meteor) in meteor_query.iter() {
+        let size = sprite_loader
+            .get_sprite(&meteor.sprite_name)
+            .map_or(Vec2::ZERO, |sprite| Vec2::new(sprite.width, sprite.height));
+        // Meteors wrapped round the screen are respawned with the damage they'd taken
+        let level = damage_lib::damage_level(meteor.health_pct());
+        commands.entity(entity).with_children(|parent| {
+            for (index, scratch) in SCRATCH_SPRITES.iter().enumerate() {
+                let mut bundle = world_systems::sprite_sheet_bundle(
+                    &asset_server,
+                    &mut texture_atlases,
+                    &sprite_loader,
+                    scratch,
+                    1,
+                    1,
+                    0,
+                );
+                let offset = Vec2::new(rng.gen_range(-0.25..=0.25), rng.gen_range(-0.25..=0.25));
+                bundle.transform = Transform::from_translation((offset * size).extend(0.01))
+                    .with_rotation(Quat::from_rotation_z(rng.gen_range(0.0..2. * PI)))
+                    .with_scale(Vec3::splat(METEOR_SCRATCH_SCALE));
+                let overlay = DamageOverlay {
+                    level: index + 1,
+                    stacks: true,
+                };
+                bundle.visibility = if overlay.shown_at(level) {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                };
+                parent.spawn((bundle, overlay));
+            }
+        });
+    }
+}
+
 pub fn despawn_meteor(mut commands: Commands, mut meteor_query: Query<Entity, With<Meteor>>) {
     for entity in meteor_query.iter_mut() {
         _despawn(&mut commands, entity);
@@ -218,7 +267,7 @@ pub fn despawn_meteor(mut commands: Commands, mut meteor_query: Query<Entity, Wi
 }
 
 fn _despawn(commands: &mut Commands, entity: Entity) {
-    commands.entity(entity).despawn();
+    commands.entity(entity).despawn_recursive();
 }
 
 pub fn handle_meteor_intersections_with_wall(
@@ -289,50 +338,63 @@ pub fn handle_meteor_intersections_with_wall(
                     .with_velocity(velocity)
                     .with_density(density),
                 transform,
-                None::<SpriteBundle>,
+                Some(Faction::Neutral.bundle()),
             );
         }
     }
 }
 
+/// Explosive shots only hurt meteors through their explosion
 pub fn handle_weapon_collision(
+    shot_query: Query<(&Weapon, &ShapeHits), Without<Explosive>>,
+    mut meteor_query: Query<&mut Meteor>,
+) {
+    for (shot, hits) in shot_query.iter() {
+        if let Some(hit) = shots_systems::shot_hit(hits) {
+            if let Ok(mut meteor) = meteor_query.get_mut(hit.entity) {
+                meteor.damage(shot);
+            }
+        }
+    }
+}
+
+/// Breaks up every meteor whose health has run out, whatever damaged it
+pub fn handle_meteor_destruction(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
     sprite_loader: Res<XMLSpriteSheetLoader>,
-    shot_query: Query<(Entity, &Weapon, &ShapeHits)>,
-    mut meteor_query: Query<(&mut Meteor, &Transform)>,
+    meteor_query: Query<(Entity, &Meteor, &Transform)>,
     window_query: Query<&Window, With<PrimaryWindow>>,
+    mut meteor_destroyed_events: EventWriter<MeteorDestroyed>,
 ) {
     let window = window_query.get_single().unwrap();
-    for (shot_entity, shot, hits) in shot_query.iter() {
-        if let Some(hit) = hits.iter().find(|&&hit| hit.time_of_impact <= 0.1) {
-            if let Ok((mut meteor, transform)) = meteor_query.get_mut(hit.entity) {
-                meteor.damage(shot);
-                if meteor.is_dead() {
-                    commands.entity(hit.entity).despawn();
-                    for new_meteors in create_new_meteors_after_destruction(
-                        &meteor,
-                        transform,
-                        &sprite_loader,
-                        window.width(),
-                        window.height(),
-                    )
-                    .iter()
-                    {
-                        spawn_meteor_at_position(
-                            &mut commands,
-                            &asset_server,
-                            &mut texture_atlases,
-                            &sprite_loader,
-                            new_meteors.0.clone(),
-                            new_meteors.1,
-                        )
-                    }
-                }
-            }
-            commands.entity(shot_entity).despawn();
-            break;
+    for (entity, meteor, transform) in meteor_query.iter() {
+        if !meteor.is_dead() {
+            continue;
+        }
+        commands.entity(entity).despawn_recursive();
+        meteor_destroyed_events.send(MeteorDestroyed {
+            position: transform.translation.truncate(),
+            meteor_type: meteor.meteor_type(),
+        });
+        for new_meteors in create_new_meteors_after_destruction(
+            meteor,
+            transform,
+            &sprite_loader,
+            window.width(),
+            window.height(),
+        )
+        .iter()
+        {
+            spawn_meteor_at_position(
+                &mut commands,
+                &asset_server,
+                &mut texture_atlases,
+                &sprite_loader,
+                new_meteors.0.clone(),
+                new_meteors.1,
+            )
         }
     }
 }
@@ -2,23 +2,42 @@ use bevy::app::App;
 use bevy::prelude::*;
 use bevy_xpbd_2d::plugins::{PhysicsDebugPlugin, PhysicsPlugins};
 
+mod bosses;
+pub mod controls;
 pub mod damage;
+mod enemies;
 mod meteors;
 mod planets;
 pub mod player;
+mod powerups;
+mod radar;
+mod score;
 mod shots;
 pub(crate) mod sprite_loader;
 mod states;
+mod steering;
 mod systems;
+mod turrets;
 pub mod util;
+mod waves;
 pub mod world;
 
 use super::states::AppState;
+use bosses::BossesPlugin;
+use controls::ControlsPlugin;
+use damage::DamagePlugin;
+use enemies::EnemiesPlugin;
 use meteors::*;
 use planets::*;
 use player::*;
+use powerups::PowerUpsPlugin;
+use radar::RadarPlugin;
+use score::ScorePlugin;
 use shots::WeaponFirePlugin;
 use sprite_loader::mapper::XMLSpriteSheetLoader;
+use steering::SteeringPlugin;
+use turrets::TurretsPlugin;
+use waves::WavesPlugin;
 use world::*;
 
 use states::*;
@@ -47,9 +66,19 @@ impl Plugin for GamePlugin {
             // .add_plugins((PhysicsPlugins::default(), PhysicsDebugPlugin::default()))
             .add_plugins((PhysicsPlugins::default()))
             .add_plugins((WorldPlugin {}))
+            .add_plugins(ControlsPlugin {})
+            .add_plugins(DamagePlugin {})
             .add_plugins(PlayerShipPlugin {})
             .add_plugins(WeaponFirePlugin {})
             .add_plugins(MeteorPlugin {})
-            .add_plugins(PlanetsPlugin {});
+            .add_plugins(SteeringPlugin {})
+            .add_plugins(EnemiesPlugin {})
+            .add_plugins(WavesPlugin {})
+            .add_plugins(BossesPlugin {})
+            .add_plugins(PlanetsPlugin {})
+            .add_plugins(TurretsPlugin {})
+            .add_plugins(PowerUpsPlugin {})
+            .add_plugins(RadarPlugin {})
+            .add_plugins(ScorePlugin {});
     }
 }
@@ -24,6 +24,10 @@ impl Planet {
             health: PLANET_HEALTH,
         }
     }
+    pub fn health_pct(&self) -> f32 {
+        self.health / PLANET_HEALTH
+    }
+
     pub fn gravity(&self, other_density: f32) -> f32 {
         GRAVITATIONAL_CONSTANT * self.density * other_density
     }
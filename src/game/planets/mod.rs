@@ -4,7 +4,7 @@ pub mod components;
 mod systems;
 
 use crate::game::states::SimulationState;
-use crate::states::AppState;
+use crate::states::{AppState, GameMode};
 use systems::*;
 
 pub const MAIN_PLANET_RADIUS: f32 = 100.;
@@ -24,6 +24,16 @@ impl Plugin for PlanetsPlugin {
                 )
                     .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
             )
+            .add_systems(
+                Update,
+                (
+                    handle_meteor_impacts_on_planets,
+                    end_game_when_planet_destroyed,
+                    render_planet_health,
+                )
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running)))
+                    .run_if(resource_equals(GameMode::PlanetDefence)),
+            )
             .add_systems(OnExit(AppState::Game), despawn_planets);
     }
 }
@@ -3,10 +3,13 @@ use bevy::window::PrimaryWindow;
 use bevy_xpbd_2d::prelude::*;
 
 use super::{MAIN_PLANET_DENSITY, MAIN_PLANET_RADIUS};
+use crate::game::damage::components::Faction;
+use crate::game::damage::Damageable;
 use crate::game::meteors::components::Meteor;
 use crate::game::player::components::PlayerShip;
 
 use super::components::*;
+use crate::states::AppState;
 
 pub fn spawn_planets(mut commands: Commands, window_query: Query<&Window, With<PrimaryWindow>>) {
     let window = window_query.get_single().unwrap();
@@ -27,7 +30,8 @@ pub fn spawn_planets(mut commands: Commands, window_query: Query<&Window, With<P
             planet,
         ))
         .insert(RigidBody::Static)
-        .insert(Collider::ball(radius));
+        .insert(Collider::ball(radius))
+        .insert(Faction::Neutral.bundle());
 }
 
 pub fn despawn_planets(mut commands: Commands, planet_query: Query<Entity, With<Planet>>) {
@@ -42,6 +46,44 @@ pub fn render_planets(mut gizmos: Gizmos, planet_query: Query<&Planet>) {
     }
 }
 
+/// In planet defence every meteor that reaches a planet breaks up against it
+pub fn handle_meteor_impacts_on_planets(
+    mut planet_query: Query<(&mut Planet, &CollidingEntities)>,
+    mut meteor_query: Query<&mut Meteor>,
+) {
+    for (mut planet, colliding_entities) in planet_query.iter_mut() {
+        for entity in colliding_entities.iter() {
+            if let Ok(mut meteor) = meteor_query.get_mut(*entity) {
+                if meteor.is_dead() {
+                    continue;
+                }
+                planet.damage(&*meteor);
+                meteor.damage(&*planet);
+            }
+        }
+    }
+}
+
+pub fn end_game_when_planet_destroyed(
+    planet_query: Query<&Planet>,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if planet_query.iter().any(|planet| planet.is_dead()) {
+        info!("The planet has fallen");
+        app_state_next_state.set(AppState::GameOver);
+    }
+}
+
+pub fn render_planet_health(mut gizmos: Gizmos, planet_query: Query<&Planet>) {
+    for planet in planet_query.iter() {
+        if !planet.is_dead() {
+            let start = planet.coordinates - Vec2::new(planet.radius, planet.radius + 20.);
+            let end = start + Vec2::X * 2. * planet.radius * planet.health_pct();
+            gizmos.line_2d(start, end, Color::LIME_GREEN);
+        }
+    }
+}
+
 pub fn simulate_meteor_gravity_toward_planets(
     mut commands: Commands,
     planet_query: Query<&Planet>,
@@ -1,12 +1,19 @@
-use crate::damage::{Damage, Damageable};
+use crate::damage::{Damage, Damageable, HealthPct};
 use crate::game::meteors::METEOR_SPAWN_TIME;
-use crate::game::player::PLAYER_LIVES;
+use crate::game::player::{
+    HYPERSPACE_COOLDOWN, PLAYER_LIVES, SPAWN_PROTECTION_BLINK_INTERVAL, SPAWN_PROTECTION_TIME,
+};
 use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct PlayerShip {
     pub density: f32,
     pub health: f32,
+    pub max_health: f32,
+    /// Thrust force from the engines
+    pub acceleration: f32,
+    /// Half the ship's width in sprite pixels, used to wrap it round the screen edges
+    pub radius: f32,
 }
 
 impl Damageable for PlayerShip {
@@ -19,6 +26,24 @@ impl Damageable for PlayerShip {
     }
 }
 
+/// How hard the engines are pushing, from -1 in full reverse to 1 at full thrust
+#[derive(Component, Default)]
+pub struct Throttle(pub f32);
+
+/// Engine flame drawn behind the ship, or ahead of it for the reverse thrusters
+#[derive(Component)]
+pub struct Thruster {
+    pub reverse: bool,
+    /// Flame scale at full throttle
+    pub size: f32,
+}
+
+impl HealthPct for PlayerShip {
+    fn health_pct(&self) -> f32 {
+        (self.health / self.max_health).max(0.)
+    }
+}
+
 #[derive(Resource)]
 pub struct PlayerLives {
     pub lives: i8,
@@ -31,3 +56,73 @@ impl Default for PlayerLives {
         }
     }
 }
+
+/// Cooldown between hyperspace jumps
+#[derive(Component)]
+pub struct Hyperspace {
+    pub cooldown: Timer,
+}
+
+impl Default for Hyperspace {
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(HYPERSPACE_COOLDOWN, TimerMode::Once);
+        cooldown.tick(cooldown.duration());
+        Hyperspace { cooldown }
+    }
+}
+
+/// Ignores damage until the timer finishes
+#[derive(Component)]
+pub struct Invulnerable {
+    pub timer: Timer,
+    /// Flashes the ship on and off while it lasts, so spawn protection can be seen
+    pub blink: Option<Timer>,
+}
+
+impl Invulnerable {
+    pub fn from_seconds(seconds: f32) -> Self {
+        Invulnerable {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+            blink: None,
+        }
+    }
+
+    /// Makes sure at least `seconds` are left, without cutting short a longer protection
+    pub fn extend_to(&mut self, seconds: f32) {
+        if self.timer.remaining_secs() < seconds {
+            let elapsed = self.timer.elapsed_secs();
+            self.timer
+                .set_duration(std::time::Duration::from_secs_f32(elapsed + seconds));
+        }
+    }
+
+    pub fn spawn_protection() -> Self {
+        Invulnerable {
+            blink: Some(Timer::from_seconds(
+                SPAWN_PROTECTION_BLINK_INTERVAL,
+                TimerMode::Repeating,
+            )),
+            ..Invulnerable::from_seconds(SPAWN_PROTECTION_TIME)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_extending_invulnerability_keeps_the_longer_protection() {
+        let mut invulnerable = Invulnerable::spawn_protection();
+        invulnerable.timer.tick(Duration::from_secs_f32(0.5));
+        invulnerable.extend_to(1.);
+        assert!(invulnerable.timer.remaining_secs() > 1.);
+        assert!(invulnerable.blink.is_some());
+
+        let mut invulnerable = Invulnerable::from_seconds(1.);
+        invulnerable.timer.tick(Duration::from_secs_f32(0.8));
+        invulnerable.extend_to(1.);
+        assert!((invulnerable.timer.remaining_secs() - 1.).abs() < 1e-4);
+    }
+}
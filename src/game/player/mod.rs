@@ -1,33 +1,104 @@
 use bevy::prelude::*;
 
 pub mod components;
+pub mod resources;
 mod systems;
 
+use crate::game::damage::systems::update_damage_overlays;
 use crate::game::states::SimulationState;
 use crate::states::AppState;
-use components::PlayerLives;
+use components::{PlayerLives, PlayerShip};
+use resources::{PendingRespawn, ShipLoadout, ShipPartRegistry};
 use systems::*;
 
-pub const PLAYER_SHIP: &str = "playerShip2_orange.png";
 pub const PLAYER_LIVES: i8 = 3;
+pub const PLAYER_MAX_LIVES: i8 = 9;
+pub const SHIP_PARTS_FILE: &str = "ships/parts.json";
+pub const SHIP_FRAMES_FILE: &str = "ships/frames.json";
+// colours every stock frame comes in on the sprite sheet
+pub const PLAYER_SHIP_COLOURS: [&str; 4] = ["orange", "blue", "green", "red"];
+pub const PLAYER_ROTATION_SPEED: f32 = 7.0;
+
+// ships spawn this far across and up the screen
+pub const PLAYER_SPAWN_POSITION_REL_TO_WINDOW: (f32, f32) = (1. / 3., 1. / 3.);
+// distance to keep from any meteor before respawning
+pub const PLAYER_SPAWN_CLEARANCE: f32 = 80.0;
+pub const SPAWN_PROTECTION_TIME: f32 = 3.0;
+pub const SPAWN_PROTECTION_BLINK_INTERVAL: f32 = 0.15;
+
+pub const THRUSTER_SPRITE: &str = "fire08.png";
+pub const REVERSE_THRUSTER_SPRITE: &str = "fire01.png";
+// engine thrust that draws the main flame at its natural size
+pub const THRUSTER_FULL_FLAME_THRUST: f32 = 35.0;
+pub const REVERSE_THRUSTER_SCALE: f32 = 0.5;
+pub const THRUSTER_FLICKER: f32 = 0.15;
+
+pub const HYPERSPACE_COOLDOWN: f32 = 5.0;
+pub const HYPERSPACE_MALFUNCTION_CHANCE: f32 = 0.05;
+pub const HYPERSPACE_INVULNERABILITY_TIME: f32 = 1.0;
+// distance to keep from any meteor or planet when picking where to jump to
+pub const HYPERSPACE_CLEARANCE: f32 = 60.0;
+pub const HYPERSPACE_JUMP_ATTEMPTS: usize = 30;
+// keeps jumps out of the outer 10% of the screen so the ship doesn't land on a wall
+pub const HYPERSPACE_RANGE_REL_TO_WINDOW: (f32, f32) = (0.1, 0.9);
+
+/// Everything that can kill the ship runs before deaths are counted, so none are missed
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
+pub enum PlayerSystemSet {
+    Damage,
+    Respawn,
+}
 
 pub struct PlayerShipPlugin;
 
 impl Plugin for PlayerShipPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PlayerLives>()
+            .init_resource::<ShipLoadout>()
+            .insert_resource(ShipPartRegistry::build(SHIP_PARTS_FILE, SHIP_FRAMES_FILE).unwrap())
             .add_systems(OnEnter(AppState::Game), spawn_ship)
+            .configure_sets(
+                Update,
+                PlayerSystemSet::Damage.before(PlayerSystemSet::Respawn),
+            )
             .add_systems(
                 Update,
                 (
                     update_player_position,
+                    update_thruster_flames.after(update_player_position),
+                    update_damage_overlays::<PlayerShip>,
                     update_player_position_from_coordinates,
+                    rotate_player_with_keys,
+                    tick_hyperspace_cooldown,
+                    tick_invulnerability,
                     handle_player_intersections_with_wall,
+                    blink_invulnerable_ships,
+                    render_player_health,
+                )
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            )
+            .add_systems(
+                Update,
+                (
+                    handle_hyperspace_jump,
                     handle_player_collision_with_meteor,
                     handle_player_collision_with_planet,
+                    handle_player_collision_with_enemy,
+                    handle_player_collision_with_boss,
+                    handle_player_hit_by_shots,
+                    handle_player_caught_in_explosions,
+                )
+                    .in_set(PlayerSystemSet::Damage)
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            )
+            .add_systems(
+                Update,
+                (
                     handle_player_respawn_on_death,
-                    render_player_health,
+                    respawn_player_when_clear.run_if(resource_exists::<PendingRespawn>()),
                 )
+                    .chain()
+                    .in_set(PlayerSystemSet::Respawn)
                     .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
             )
             .add_systems(OnExit(AppState::Game), despawn_player);
@@ -0,0 +1,364 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::fs;
+
+use crate::game::player::PLAYER_SHIP_COLOURS;
+
+/// Where a part goes on the ship, a loadout has exactly one part in every slot
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartSlot {
+    Cockpit,
+    Wings,
+    Engine,
+    Guns,
+}
+
+impl PartSlot {
+    pub const ALL: [PartSlot; 4] = [
+        PartSlot::Cockpit,
+        PartSlot::Wings,
+        PartSlot::Engine,
+        PartSlot::Guns,
+    ];
+
+    /// Draw order within the ship, wings and engine tuck under the cockpit
+    pub fn z(&self) -> f32 {
+        match self {
+            PartSlot::Wings => -0.02,
+            PartSlot::Engine => -0.01,
+            PartSlot::Cockpit => 0.,
+            PartSlot::Guns => 0.01,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PartSlot::Cockpit => "Cockpit",
+            PartSlot::Wings => "Wings",
+            PartSlot::Engine => "Engine",
+            PartSlot::Guns => "Guns",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShipPartDefinition {
+    pub name: String,
+    pub slot: PartSlot,
+    pub sprite_name: String,
+    /// Sprite pixels from the ship centre, with the nose along +y
+    pub offset: Vec2,
+    /// Also fitted mirrored on the other side of the ship
+    #[serde(default)]
+    pub mirrored: bool,
+    pub mass: f32,
+    #[serde(default)]
+    pub thrust: f32,
+    pub health: f32,
+    /// Where shots leave from, relative to the part
+    #[serde(default)]
+    pub hardpoints: Vec<Vec2>,
+}
+
+impl ShipPartDefinition {
+    fn copies(&self) -> f32 {
+        if self.mirrored {
+            2.
+        } else {
+            1.
+        }
+    }
+}
+
+/// A stock hull from the sprite sheet, flown as-is instead of being assembled from parts
+#[derive(Deserialize, Debug, Clone)]
+pub struct ShipFrameDefinition {
+    pub name: String,
+    /// Sprite name up to the colour, e.g. `playerShip1`
+    pub sprite_prefix: String,
+    pub acceleration: f32,
+    pub health: f32,
+    pub density: f32,
+    /// Where shots leave from, in sprite pixels from the ship centre
+    pub hardpoints: Vec<Vec2>,
+}
+
+impl ShipFrameDefinition {
+    pub fn sprite_name(&self, colour: &str) -> String {
+        format!("{}_{colour}.png", self.sprite_prefix)
+    }
+
+    /// The cracks drawn over the hull, `level` from 1 to 3
+    pub fn damage_sprite_name(&self, level: usize) -> String {
+        format!("{}_damage{level}.png", self.sprite_prefix)
+    }
+}
+
+/// A part as fitted to the ship, mirrored parts appear twice
+pub struct FittedPart<'a> {
+    pub definition: &'a ShipPartDefinition,
+    pub offset: Vec2,
+    pub flip_x: bool,
+}
+
+/// One of the sprites the ship is drawn with, a stock frame is a single hull sprite
+pub struct HullSprite {
+    pub sprite_name: String,
+    pub offset: Vec2,
+    pub z: f32,
+    pub flip_x: bool,
+}
+
+/// Everything the hangar offers: stock frames, and parts grouped by slot in the order they're offered
+#[derive(Resource)]
+pub struct ShipPartRegistry {
+    parts: Vec<ShipPartDefinition>,
+    frames: Vec<ShipFrameDefinition>,
+}
+
+impl ShipPartRegistry {
+    pub fn build(parts_file: &str, frames_file: &str) -> Result<ShipPartRegistry, std::io::Error> {
+        let parts_file = format!("assets/{parts_file}");
+        let json_string = fs::read_to_string(parts_file)?;
+        let parts: Vec<ShipPartDefinition> = serde_json::from_str(&json_string)?;
+        let frames_file = format!("assets/{frames_file}");
+        let json_string = fs::read_to_string(frames_file)?;
+        let frames: Vec<ShipFrameDefinition> = serde_json::from_str(&json_string)?;
+        if let Some(slot) = PartSlot::ALL
+            .iter()
+            .find(|slot| !parts.iter().any(|part| part.slot == **slot))
+        {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("no parts for the {} slot", slot.label()),
+            ));
+        }
+        Ok(ShipPartRegistry { parts, frames })
+    }
+
+    pub fn frames(&self) -> &[ShipFrameDefinition] {
+        &self.frames
+    }
+
+    pub fn options(&self, slot: PartSlot) -> Vec<&ShipPartDefinition> {
+        self.parts.iter().filter(|part| part.slot == slot).collect()
+    }
+
+    /// Wraps round the parts for `slot`
+    pub fn get(&self, slot: PartSlot, index: usize) -> &ShipPartDefinition {
+        let options = self.options(slot);
+        options[index % options.len()]
+    }
+}
+
+/// A life lost and waiting for somewhere clear to bring the next ship in
+#[derive(Resource, Default)]
+pub struct PendingRespawn;
+
+/// The ship picked in the hangar: either a stock frame, or an index into each slot's options
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct ShipLoadout {
+    /// `None` flies the ship assembled from the parts below
+    pub frame: Option<usize>,
+    /// Index into `PLAYER_SHIP_COLOURS`, only stock frames come in colours
+    pub colour: usize,
+    pub cockpit: usize,
+    pub wings: usize,
+    pub engine: usize,
+    pub guns: usize,
+}
+
+/// What a loadout adds up to
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShipStats {
+    pub mass: f32,
+    pub thrust: f32,
+    pub health: f32,
+    pub hardpoints: Vec<Vec2>,
+    /// Half the width of the widest part of the ship, in sprite pixels
+    pub radius: f32,
+}
+
+impl ShipLoadout {
+    pub fn index(&self, slot: PartSlot) -> usize {
+        match slot {
+            PartSlot::Cockpit => self.cockpit,
+            PartSlot::Wings => self.wings,
+            PartSlot::Engine => self.engine,
+            PartSlot::Guns => self.guns,
+        }
+    }
+
+    /// Steps to the next or previous part for `slot`, wrapping round
+    pub fn cycle(&mut self, slot: PartSlot, step: i32, registry: &ShipPartRegistry) {
+        let count = registry.options(slot).len() as i32;
+        let index = (self.index(slot) as i32 + step).rem_euclid(count) as usize;
+        match slot {
+            PartSlot::Cockpit => self.cockpit = index,
+            PartSlot::Wings => self.wings = index,
+            PartSlot::Engine => self.engine = index,
+            PartSlot::Guns => self.guns = index,
+        }
+    }
+
+    /// Steps through the stock frames, with the custom ship before the first
+    pub fn cycle_frame(&mut self, step: i32, registry: &ShipPartRegistry) {
+        let count = registry.frames().len() as i32 + 1;
+        let current = self.frame.map_or(0, |frame| frame as i32 + 1);
+        let index = (current + step).rem_euclid(count);
+        self.frame = if index == 0 {
+            None
+        } else {
+            Some(index as usize - 1)
+        };
+    }
+
+    pub fn cycle_colour(&mut self, step: i32) {
+        let count = PLAYER_SHIP_COLOURS.len() as i32;
+        self.colour = (self.colour as i32 + step).rem_euclid(count) as usize;
+    }
+
+    pub fn colour(&self) -> &'static str {
+        PLAYER_SHIP_COLOURS[self.colour % PLAYER_SHIP_COLOURS.len()]
+    }
+
+    pub fn frame<'a>(&self, registry: &'a ShipPartRegistry) -> Option<&'a ShipFrameDefinition> {
+        self.frame.and_then(|frame| registry.frames().get(frame))
+    }
+
+    pub fn part<'a>(
+        &self,
+        slot: PartSlot,
+        registry: &'a ShipPartRegistry,
+    ) -> &'a ShipPartDefinition {
+        registry.get(slot, self.index(slot))
+    }
+
+    pub fn fitted_parts<'a>(&self, registry: &'a ShipPartRegistry) -> Vec<FittedPart<'a>> {
+        let mut fitted = Vec::new();
+        for slot in PartSlot::ALL {
+            let definition = self.part(slot, registry);
+            fitted.push(FittedPart {
+                definition,
+                offset: definition.offset,
+                flip_x: false,
+            });
+            if definition.mirrored {
+                fitted.push(FittedPart {
+                    definition,
+                    offset: Vec2::new(-definition.offset.x, definition.offset.y),
+                    flip_x: true,
+                });
+            }
+        }
+        fitted
+    }
+
+    pub fn hull_sprites(&self, registry: &ShipPartRegistry) -> Vec<HullSprite> {
+        if let Some(frame) = self.frame(registry) {
+            return vec![HullSprite {
+                sprite_name: frame.sprite_name(self.colour()),
+                offset: Vec2::ZERO,
+                z: 0.,
+                flip_x: false,
+            }];
+        }
+        self.fitted_parts(registry)
+            .iter()
+            .map(|fitted| HullSprite {
+                sprite_name: fitted.definition.sprite_name.clone(),
+                offset: fitted.offset,
+                z: fitted.definition.slot.z(),
+                flip_x: fitted.flip_x,
+            })
+            .collect()
+    }
+
+    pub fn stats(&self, registry: &ShipPartRegistry, sizes: impl Fn(&str) -> Vec2) -> ShipStats {
+        if let Some(frame) = self.frame(registry) {
+            return ShipStats {
+                mass: frame.density,
+                thrust: frame.acceleration,
+                health: frame.health,
+                hardpoints: frame.hardpoints.clone(),
+                radius: sizes(&frame.sprite_name(self.colour())).x / 2.,
+            };
+        }
+        let parts: Vec<&ShipPartDefinition> = PartSlot::ALL
+            .iter()
+            .map(|slot| self.part(*slot, registry))
+            .collect();
+        let hardpoints = self
+            .fitted_parts(registry)
+            .iter()
+            .flat_map(|fitted| {
+                let sign = if fitted.flip_x { -1. } else { 1. };
+                fitted.definition.hardpoints.iter().map(move |hardpoint| {
+                    fitted.offset + Vec2::new(hardpoint.x * sign, hardpoint.y)
+                })
+            })
+            .collect();
+        let radius = self
+            .fitted_parts(registry)
+            .iter()
+            .map(|fitted| fitted.offset.x.abs() + sizes(&fitted.definition.sprite_name).x / 2.)
+            .fold(0., f32::max);
+        ShipStats {
+            mass: parts.iter().map(|part| part.mass * part.copies()).sum(),
+            thrust: parts.iter().map(|part| part.thrust * part.copies()).sum(),
+            health: parts.iter().map(|part| part.health * part.copies()).sum(),
+            hardpoints,
+            radius,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::player::{SHIP_FRAMES_FILE, SHIP_PARTS_FILE};
+
+    #[test]
+    fn test_loadout_stats_count_mirrored_parts_twice() {
+        let registry = ShipPartRegistry::build(SHIP_PARTS_FILE, SHIP_FRAMES_FILE).unwrap();
+        let loadout = ShipLoadout::default();
+        let stats = loadout.stats(&registry, |_| Vec2::new(10., 10.));
+        let wings = loadout.part(PartSlot::Wings, &registry);
+        assert!(wings.mirrored);
+        let expected_mass: f32 = PartSlot::ALL
+            .iter()
+            .map(|slot| loadout.part(*slot, &registry))
+            .map(|part| part.mass * if part.mirrored { 2. } else { 1. })
+            .sum();
+        assert!((stats.mass - expected_mass).abs() < 1e-6);
+        assert!(stats.thrust > 0.);
+        assert!(!stats.hardpoints.is_empty());
+    }
+
+    #[test]
+    fn test_cycle_loadout_wraps() {
+        let registry = ShipPartRegistry::build(SHIP_PARTS_FILE, SHIP_FRAMES_FILE).unwrap();
+        let mut loadout = ShipLoadout::default();
+        loadout.cycle(PartSlot::Engine, -1, &registry);
+        assert_eq!(loadout.engine, registry.options(PartSlot::Engine).len() - 1);
+        loadout.cycle(PartSlot::Engine, 1, &registry);
+        assert_eq!(loadout.engine, 0);
+    }
+
+    #[test]
+    fn test_stock_frames_have_distinct_stats() {
+        let registry = ShipPartRegistry::build(SHIP_PARTS_FILE, SHIP_FRAMES_FILE).unwrap();
+        let mut loadout = ShipLoadout::default();
+        let mut seen: Vec<(f32, f32, f32)> = Vec::new();
+        for _ in registry.frames() {
+            loadout.cycle_frame(1, &registry);
+            let stats = loadout.stats(&registry, |_| Vec2::new(10., 10.));
+            let key = (stats.mass, stats.thrust, stats.health);
+            assert!(!seen.contains(&key));
+            seen.push(key);
+            assert_eq!(loadout.hull_sprites(&registry).len(), 1);
+        }
+        loadout.cycle_frame(1, &registry);
+        assert!(loadout.frame.is_none());
+    }
+}
@@ -1,14 +1,26 @@
 use ::bevy::prelude::*;
+use bevy::sprite::Anchor;
 use bevy::window::PrimaryWindow;
 use bevy_xpbd_2d::prelude::*;
+use rand::{random, thread_rng, Rng};
 use std::f32::consts::PI;
 
-use crate::game::damage::Damageable;
+use crate::game::controls::resources::{
+    ActionState, ActiveGamepad, AimSource, ControlMode, ControlSettings, InputAction, StickAim,
+};
+use crate::game::controls::systems as controls_systems;
+use crate::game::damage::components::{DamageOverlay, Faction, Shield};
+use crate::game::damage::events::Explosion;
+use crate::game::damage::resources::FriendlyFire;
+use crate::game::damage::{Damageable, SCRATCH_SPRITES};
 use crate::game::meteors::components::Meteor;
 use crate::game::planets::components::Planet;
 
+use crate::game::bosses::components::BossPart;
 use crate::game::damage::lib as damage_lib;
+use crate::game::enemies::components::Enemy;
 use crate::game::shots::components::*;
+use crate::game::shots::systems as shots_systems;
 use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
 use crate::game::world;
 use crate::game::world::components::{BottomWall, LeftWall, RightWall, TopWall};
@@ -17,176 +29,547 @@ use crate::game::world::systems as world_systems;
 use crate::states::AppState;
 
 use super::components::*;
-use super::PLAYER_SHIP;
+use super::resources::{ShipLoadout, ShipPartRegistry};
+use super::*;
 
-pub const PLAYER_ROTATION_SPEED: f32 = 7.0;
-pub const PLAYER_ACCELERATION: f32 = 35.0;
-pub const PLAYER_SHIP_DENSITY: f32 = 0.9;
 pub const PLAYER_SHIP_SCALE: f32 = 0.4;
 
-pub const PLAYER_HEALTH: f32 = 2000.;
-
 pub fn spawn_ship(
     mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
     asset_server: Res<AssetServer>,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
     sprite_loader: Res<XMLSpriteSheetLoader>,
+    part_registry: Res<ShipPartRegistry>,
+    loadout: Res<ShipLoadout>,
 ) {
     let window = window_query.get_single().unwrap();
-    world_systems::spawn_sprite_frame_at_position(
+    spawn_ship_at(
         &mut commands,
         &asset_server,
         &mut texture_atlases,
         &sprite_loader,
-        PLAYER_SHIP,
-        1,
-        1,
-        0,
-        PLAYER_SHIP_SCALE,
-        PlayerShip {
-            density: PLAYER_SHIP_DENSITY,
-            health: PLAYER_HEALTH,
-        },
-        world::RigidBodyBehaviors::default()
-            .with_velocity(LinearVelocity::ZERO)
-            .with_external_force(ExternalForce::default())
-            .with_density(PLAYER_SHIP_DENSITY),
-        Transform::from_xyz(window.width() / 3., window.height() / 3., 0.0),
-        Some(WeaponFireTimer { ..default() }),
+        &part_registry,
+        &loadout,
+        spawn_position(window),
     );
 }
 
+/// Assembles the ship from the loadout picked in the hangar
+fn spawn_ship_at(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
+    sprite_loader: &Res<XMLSpriteSheetLoader>,
+    part_registry: &ShipPartRegistry,
+    loadout: &ShipLoadout,
+    position: Vec2,
+) {
+    let sprite_size = |sprite_name: &str| {
+        sprite_loader
+            .get_sprite(sprite_name)
+            .map_or(Vec2::ZERO, |sprite| Vec2::new(sprite.width, sprite.height))
+    };
+    let stats = loadout.stats(part_registry, sprite_size);
+    let hull_sprites = loadout.hull_sprites(part_registry);
+    let frame = loadout.frame(part_registry);
+    let collider = match frame {
+        Some(_) => sprite_loader
+            .get_sprite_collider_or_bounds(&hull_sprites[0].sprite_name, 0, true)
+            .unwrap(),
+        None => Collider::compound(
+            hull_sprites
+                .iter()
+                .map(|hull| {
+                    let size = sprite_size(&hull.sprite_name);
+                    (hull.offset, 0., Collider::cuboid(size.x, size.y))
+                })
+                .collect(),
+        ),
+    };
+    let hull_bounds: Vec<(f32, f32)> = hull_sprites
+        .iter()
+        .map(|hull| {
+            let size = sprite_size(&hull.sprite_name);
+            (hull.offset.y - size.y / 2., hull.offset.y + size.y / 2.)
+        })
+        .collect();
+    let tail = hull_bounds.iter().map(|bounds| bounds.0).fold(0., f32::min);
+    let nose = hull_bounds.iter().map(|bounds| bounds.1).fold(0., f32::max);
+    let hardpoints = stats
+        .hardpoints
+        .iter()
+        .map(|hardpoint| *hardpoint * PLAYER_SHIP_SCALE)
+        .collect();
+
+    let ship_entity = commands
+        .spawn((
+            SpatialBundle::from_transform(
+                Transform::from_translation(position.extend(0.))
+                    .with_scale(Vec3::splat(PLAYER_SHIP_SCALE)),
+            ),
+            PlayerShip {
+                density: stats.mass,
+                health: stats.health,
+                max_health: stats.health,
+                acceleration: stats.thrust,
+                radius: stats.radius,
+            },
+            collider,
+        ))
+        .insert((
+            WeaponFireTimer { ..default() },
+            EquippedWeapon::default(),
+            WeaponReserves::default(),
+            ShipEnergy::default(),
+            Hyperspace::default(),
+            Hardpoints(hardpoints),
+            Throttle::default(),
+            Invulnerable::spawn_protection(),
+            Faction::Player.bundle(),
+        ))
+        .with_children(|parent| {
+            for hull in hull_sprites.iter() {
+                let mut bundle = world_systems::sprite_sheet_bundle(
+                    asset_server,
+                    texture_atlases,
+                    sprite_loader,
+                    &hull.sprite_name,
+                    1,
+                    1,
+                    0,
+                );
+                bundle.sprite.flip_x = hull.flip_x;
+                bundle.transform = Transform::from_translation(hull.offset.extend(hull.z));
+                parent.spawn(bundle);
+            }
+            // Stock frames have cracked hulls drawn for them, assembled ships get scratched parts
+            for (index, scratch) in SCRATCH_SPRITES.iter().enumerate() {
+                let level = index + 1;
+                let (sprite_name, offset, stacks) = match frame {
+                    Some(frame) => (frame.damage_sprite_name(level), Vec2::ZERO, false),
+                    None => {
+                        let hull = &hull_sprites[index % hull_sprites.len()];
+                        let size = sprite_size(&hull.sprite_name);
+                        let jitter = Vec2::new(
+                            thread_rng().gen_range(-0.25..=0.25),
+                            thread_rng().gen_range(-0.25..=0.25),
+                        );
+                        (scratch.to_string(), hull.offset + jitter * size, true)
+                    }
+                };
+                let mut bundle = world_systems::sprite_sheet_bundle(
+                    asset_server,
+                    texture_atlases,
+                    sprite_loader,
+                    &sprite_name,
+                    1,
+                    1,
+                    0,
+                );
+                bundle.transform = Transform::from_translation(offset.extend(0.005));
+                bundle.visibility = Visibility::Hidden;
+                parent.spawn((bundle, DamageOverlay { level, stacks }));
+            }
+            let flame_size = stats.thrust / THRUSTER_FULL_FLAME_THRUST;
+            let mut thrusters = vec![(
+                THRUSTER_SPRITE,
+                Transform::from_xyz(0., tail, -0.03),
+                Thruster {
+                    reverse: false,
+                    size: flame_size,
+                },
+            )];
+            for side in [-1., 1.] {
+                thrusters.push((
+                    REVERSE_THRUSTER_SPRITE,
+                    Transform::from_xyz(side * stats.radius / 2., nose / 2., -0.03)
+                        .with_rotation(Quat::from_rotation_z(PI)),
+                    Thruster {
+                        reverse: true,
+                        size: flame_size * REVERSE_THRUSTER_SCALE,
+                    },
+                ));
+            }
+            for (sprite_name, transform, thruster) in thrusters {
+                let mut bundle = world_systems::sprite_sheet_bundle(
+                    asset_server,
+                    texture_atlases,
+                    sprite_loader,
+                    sprite_name,
+                    1,
+                    1,
+                    0,
+                );
+                // Flames grow out from the nozzle
+                bundle.sprite.anchor = Anchor::TopCenter;
+                bundle.transform = transform;
+                bundle.visibility = Visibility::Hidden;
+                parent.spawn((bundle, thruster));
+            }
+        })
+        .id();
+    world::RigidBodyBehaviors::default()
+        .with_velocity(LinearVelocity::ZERO)
+        .with_external_force(ExternalForce::default())
+        .with_density(stats.mass)
+        .add_to_entity(ship_entity, commands);
+}
+
 pub fn despawn_player(mut commands: Commands, player_ship_query: Query<Entity, With<PlayerShip>>) {
     if let Ok(player_entity) = player_ship_query.get_single() {
         _despawn(&mut commands, player_entity);
     }
+    commands.remove_resource::<PendingRespawn>();
+}
+
+fn spawn_position(window: &Window) -> Vec2 {
+    let (x, y) = PLAYER_SPAWN_POSITION_REL_TO_WINDOW;
+    Vec2::new(window.width() * x, window.height() * y)
 }
 
 fn _despawn(commands: &mut Commands, entity: Entity) {
-    commands.entity(entity).despawn();
+    commands.entity(entity).despawn_recursive();
 }
 
 pub fn update_player_position(
-    keyboard_input: Res<Input<KeyCode>>,
-    mut player_ship_query: Query<
-        (&Transform, &mut LinearVelocity, &mut ExternalForce),
-        With<PlayerShip>,
-    >,
+    action_state: Res<ActionState>,
+    active_gamepad: Res<ActiveGamepad>,
+    axes: Res<Axis<GamepadAxis>>,
+    button_axes: Res<Axis<GamepadButton>>,
+    mut gamepad_thrusting: Local<bool>,
+    mut player_ship_query: Query<(
+        &PlayerShip,
+        &Transform,
+        &mut LinearVelocity,
+        &mut ExternalForce,
+        &mut Throttle,
+    )>,
 ) {
-    if let Ok((transform, mut velocity, mut forces)) = player_ship_query.get_single_mut() {
-        if keyboard_input.pressed(KeyCode::Up) || keyboard_input.pressed(KeyCode::W) {
-            let force = transform.rotation.mul_vec3(Vec3::Y) * PLAYER_ACCELERATION;
+    if let Ok((player_ship, transform, mut velocity, mut forces, mut throttle)) =
+        player_ship_query.get_single_mut()
+    {
+        throttle.0 = 0.;
+        if action_state.pressed(InputAction::Thrust) {
+            throttle.0 += 1.;
+            let force = transform.rotation.mul_vec3(Vec3::Y) * player_ship.acceleration;
             forces.apply_force(Vec2::new(force.x, force.y));
         }
 
-        if keyboard_input.just_released(KeyCode::Up) || keyboard_input.just_released(KeyCode::W) {
+        if action_state.just_released(InputAction::Thrust) {
             forces.clear();
         }
 
-        if keyboard_input.pressed(KeyCode::Down) || keyboard_input.pressed(KeyCode::S) {
-            let force = transform.rotation.mul_vec3(Vec3::Y) * -PLAYER_ACCELERATION;
+        if action_state.pressed(InputAction::Reverse) {
+            throttle.0 -= 1.;
+            let force = transform.rotation.mul_vec3(Vec3::Y) * -player_ship.acceleration;
             forces.apply_force(Vec2::new(force.x, force.y));
         }
 
-        if keyboard_input.just_released(KeyCode::Down) || keyboard_input.just_released(KeyCode::S) {
+        if action_state.just_released(InputAction::Reverse) {
             forces.clear();
         }
+
+        // Gamepad thrust is analog, so the force is replaced every frame rather than added to
+        if let Some(gamepad) = active_gamepad.0 {
+            let thrust = controls_systems::gamepad_thrust(&axes, &button_axes, gamepad);
+            if thrust != 0. {
+                let force =
+                    transform.rotation.mul_vec3(Vec3::Y) * player_ship.acceleration * thrust;
+                forces.clear();
+                forces.apply_force(Vec2::new(force.x, force.y));
+                throttle.0 = thrust;
+                *gamepad_thrusting = true;
+            } else if *gamepad_thrusting {
+                forces.clear();
+                *gamepad_thrusting = false;
+            }
+        }
+    }
+}
+
+/// Shows the engine flames while thrusting, longer the harder the engines push
+pub fn update_thruster_flames(
+    time: Res<Time>,
+    throttle_query: Query<&Throttle>,
+    mut thruster_query: Query<(&Thruster, &Parent, &mut Transform, &mut Visibility)>,
+) {
+    let flicker = 1. + THRUSTER_FLICKER * (time.elapsed_seconds() * 40.).sin();
+    for (thruster, parent, mut transform, mut visibility) in thruster_query.iter_mut() {
+        if let Ok(throttle) = throttle_query.get(parent.get()) {
+            let push = if thruster.reverse {
+                -throttle.0
+            } else {
+                throttle.0
+            };
+            if push > 0. {
+                *visibility = Visibility::Inherited;
+                transform.scale = Vec3::new(thruster.size, thruster.size * push * flicker, 1.);
+            } else {
+                *visibility = Visibility::Hidden;
+            }
+        }
     }
 }
 
 pub fn update_player_position_from_coordinates(
     coordinates: ResMut<WorldCoordinates>,
+    control_settings: Res<ControlSettings>,
+    aim_source: Res<AimSource>,
+    stick_aim: Res<StickAim>,
     mut player_ship_query: Query<&mut Transform, With<PlayerShip>>,
 ) {
+    if control_settings.mode != ControlMode::MouseAim {
+        return;
+    }
     if let Ok(mut transform) = player_ship_query.get_single_mut() {
-        let direction = coordinates.0 - Vec2::new(transform.translation.x, transform.translation.y);
+        let direction = match *aim_source {
+            AimSource::Mouse => {
+                coordinates.0 - Vec2::new(transform.translation.x, transform.translation.y)
+            }
+            AimSource::Stick => stick_aim.0,
+        };
         let angle = direction.y.atan2(direction.x);
         transform.rotation = Quat::from_rotation_z(angle - PI / 2.);
     }
 }
 
+pub fn rotate_player_with_keys(
+    action_state: Res<ActionState>,
+    control_settings: Res<ControlSettings>,
+    active_gamepad: Res<ActiveGamepad>,
+    axes: Res<Axis<GamepadAxis>>,
+    time: Res<Time>,
+    mut player_ship_query: Query<&mut Transform, With<PlayerShip>>,
+) {
+    if control_settings.mode != ControlMode::RotateKeys {
+        return;
+    }
+    if let Ok(mut transform) = player_ship_query.get_single_mut() {
+        let mut turn = 0.;
+        if action_state.pressed(InputAction::RotateLeft) {
+            turn += 1.;
+        }
+        if action_state.pressed(InputAction::RotateRight) {
+            turn -= 1.;
+        }
+        if turn == 0. {
+            if let Some(gamepad) = active_gamepad.0 {
+                turn = -controls_systems::stick_position(
+                    &axes,
+                    gamepad,
+                    GamepadAxisType::LeftStickX,
+                    GamepadAxisType::LeftStickY,
+                )
+                .x;
+            }
+        }
+        transform.rotate_z(turn * control_settings.rotation_speed * time.delta_seconds());
+    }
+}
+
 pub fn handle_player_intersections_with_wall(
-    mut commands: Commands,
     window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-    sprite_loader: Res<XMLSpriteSheetLoader>,
-    player_ship_query: Query<
-        (Entity, &Transform, &LinearVelocity, &CollidingEntities),
-        With<PlayerShip>,
-    >,
+    mut player_ship_query: Query<(&PlayerShip, &mut Transform, &CollidingEntities)>,
     left_wall_query: Query<&LeftWall>,
     right_wall_query: Query<&RightWall>,
     top_wall_query: Query<&TopWall>,
     bottom_wall_query: Query<&BottomWall>,
 ) {
     let window = window_query.get_single().unwrap();
-    if let Ok((player_ship_entity, player_ship_transform, velocity, colliding_entities)) =
-        player_ship_query.get_single()
+    if let Ok((player_ship, mut transform, colliding_entities)) = player_ship_query.get_single_mut()
     {
-        let mut should_spawn_ship = false;
-        let mut transform = player_ship_transform.clone();
-        let sprite = sprite_loader.get_sprite(PLAYER_SHIP).unwrap();
-        let radius = sprite.half_width();
+        // Moving the transform carries the ship and everything attached to it across the field,
+        // physics picks the new position up from the transform
+        let player_ship_transform = *transform;
+        let radius = player_ship.radius;
         for other_entity in colliding_entities.iter() {
-            if let Ok(_) = left_wall_query.get(*other_entity) {
+            if left_wall_query.contains(*other_entity) {
                 let distance = player_ship_transform.translation.x;
                 if distance < radius && player_ship_transform.translation.x < 0.0 {
-                    should_spawn_ship = true;
                     transform.translation.x = window.width() - radius;
                 }
-            } else if let Ok(_) = right_wall_query.get(*other_entity) {
+            } else if right_wall_query.contains(*other_entity) {
                 let distance = window.width() - player_ship_transform.translation.x;
                 if distance < radius && player_ship_transform.translation.x > window.width() {
-                    should_spawn_ship = true;
                     transform.translation.x = radius;
                 }
-            } else if let Ok(_) = top_wall_query.get(*other_entity) {
+            } else if top_wall_query.contains(*other_entity) {
                 let distance = window.height() - player_ship_transform.translation.y;
                 if distance < radius && player_ship_transform.translation.y > window.height() {
-                    should_spawn_ship = true;
                     transform.translation.y = radius;
                 }
-            } else if let Ok(_) = bottom_wall_query.get(*other_entity) {
+            } else if bottom_wall_query.contains(*other_entity) {
                 let distance = player_ship_transform.translation.y;
                 if distance < radius && player_ship_transform.translation.y < 0.0 {
-                    should_spawn_ship = true;
                     transform.translation.y = window.height() - radius;
                 }
             }
         }
+    }
+}
 
-        if should_spawn_ship {
-            _despawn(&mut commands, player_ship_entity);
-            world_systems::spawn_sprite_frame_at_position(
-                &mut commands,
-                &asset_server,
-                &mut texture_atlases,
-                &sprite_loader,
-                PLAYER_SHIP,
-                1,
-                1,
-                0,
-                PLAYER_SHIP_SCALE,
-                PlayerShip {
-                    density: PLAYER_SHIP_DENSITY,
-                    health: PLAYER_HEALTH,
-                },
-                world::RigidBodyBehaviors::default()
-                    .with_velocity(velocity.clone())
-                    .with_density(PLAYER_SHIP_DENSITY),
-                transform,
-                Some(WeaponFireTimer { ..default() }),
-            );
+pub fn handle_hyperspace_jump(
+    mut commands: Commands,
+    action_state: Res<ActionState>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    spatial_query: SpatialQuery,
+    meteor_query: Query<(), With<Meteor>>,
+    planet_query: Query<(), With<Planet>>,
+    mut player_ship_query: Query<(
+        Entity,
+        &mut PlayerShip,
+        &mut Hyperspace,
+        &mut Transform,
+        &mut LinearVelocity,
+        &mut ExternalForce,
+        Option<&mut Invulnerable>,
+    )>,
+) {
+    if !action_state.just_pressed(InputAction::Hyperspace) {
+        return;
+    }
+    let window = window_query.get_single().unwrap();
+    if let Ok((
+        entity,
+        mut player_ship,
+        mut hyperspace,
+        mut transform,
+        mut velocity,
+        mut forces,
+        invulnerable,
+    )) = player_ship_query.get_single_mut()
+    {
+        if !hyperspace.cooldown.finished() {
             return;
         }
+        if random::<f32>() < HYPERSPACE_MALFUNCTION_CHANCE {
+            hyperspace.cooldown.reset();
+            info!("Hyperspace malfunction!");
+            player_ship.health = 0.;
+            _despawn(&mut commands, entity);
+            return;
+        }
+
+        if let Some(position) = find_safe_position(
+            &spatial_query,
+            window,
+            HYPERSPACE_CLEARANCE,
+            &meteor_query,
+            &planet_query,
+        ) {
+            // Only a jump that happens costs the cooldown
+            hyperspace.cooldown.reset();
+            transform.translation.x = position.x;
+            transform.translation.y = position.y;
+            velocity.0 = Vec2::ZERO;
+            forces.clear();
+            match invulnerable {
+                Some(mut invulnerable) => invulnerable.extend_to(HYPERSPACE_INVULNERABILITY_TIME),
+                None => {
+                    commands
+                        .entity(entity)
+                        .insert(Invulnerable::from_seconds(HYPERSPACE_INVULNERABILITY_TIME));
+                }
+            }
+        }
+    }
+}
+
+/// Picks a random spot in the play field with no meteor or planet within `clearance`
+fn find_safe_position(
+    spatial_query: &SpatialQuery,
+    window: &Window,
+    clearance: f32,
+    meteor_query: &Query<(), With<Meteor>>,
+    planet_query: &Query<(), With<Planet>>,
+) -> Option<Vec2> {
+    let mut rng = thread_rng();
+    let (min, max) = HYPERSPACE_RANGE_REL_TO_WINDOW;
+    (0..HYPERSPACE_JUMP_ATTEMPTS)
+        .map(|_| {
+            Vec2::new(
+                rng.gen_range(min..max) * window.width(),
+                rng.gen_range(min..max) * window.height(),
+            )
+        })
+        .find(|position| {
+            is_clear(
+                spatial_query,
+                *position,
+                clearance,
+                meteor_query,
+                planet_query,
+            )
+        })
+}
+
+fn is_clear(
+    spatial_query: &SpatialQuery,
+    position: Vec2,
+    clearance: f32,
+    meteor_query: &Query<(), With<Meteor>>,
+    planet_query: &Query<(), With<Planet>>,
+) -> bool {
+    spatial_query
+        .shape_intersections(
+            &Collider::ball(clearance),
+            position,
+            0.,
+            SpatialQueryFilter::default(),
+        )
+        .iter()
+        .all(|entity| !meteor_query.contains(*entity) && !planet_query.contains(*entity))
+}
+
+pub fn tick_hyperspace_cooldown(mut hyperspace_query: Query<&mut Hyperspace>, time: Res<Time>) {
+    for mut hyperspace in hyperspace_query.iter_mut() {
+        hyperspace.cooldown.tick(time.delta());
+    }
+}
+
+pub fn tick_invulnerability(
+    mut commands: Commands,
+    mut invulnerable_query: Query<(Entity, &mut Invulnerable)>,
+    time: Res<Time>,
+) {
+    for (entity, mut invulnerable) in invulnerable_query.iter_mut() {
+        if invulnerable.timer.tick(time.delta()).finished() {
+            commands
+                .entity(entity)
+                .remove::<Invulnerable>()
+                .insert(Visibility::Inherited);
+        }
+    }
+}
+
+pub fn blink_invulnerable_ships(
+    mut invulnerable_query: Query<(&mut Invulnerable, &mut Visibility)>,
+    time: Res<Time>,
+) {
+    for (mut invulnerable, mut visibility) in invulnerable_query.iter_mut() {
+        if let Some(blink) = invulnerable.blink.as_mut() {
+            if blink.tick(time.delta()).just_finished() {
+                *visibility = match *visibility {
+                    Visibility::Hidden => Visibility::Inherited,
+                    _ => Visibility::Hidden,
+                };
+            }
+        }
     }
 }
 
 pub fn handle_player_collision_with_meteor(
     mut commands: Commands,
-    mut player_ship_query: Query<(Entity, &mut PlayerShip, &CollidingEntities)>,
+    mut player_ship_query: Query<
+        (
+            Entity,
+            &mut PlayerShip,
+            &CollidingEntities,
+            Option<&mut Shield>,
+        ),
+        Without<Invulnerable>,
+    >,
     meteor_query: Query<&Meteor>,
 ) {
     damage_lib::handle_collision_with_damageable(
@@ -197,7 +580,15 @@ pub fn handle_player_collision_with_meteor(
 }
 pub fn handle_player_collision_with_planet(
     mut commands: Commands,
-    mut player_ship_query: Query<(Entity, &mut PlayerShip, &CollidingEntities)>,
+    mut player_ship_query: Query<
+        (
+            Entity,
+            &mut PlayerShip,
+            &CollidingEntities,
+            Option<&mut Shield>,
+        ),
+        Without<Invulnerable>,
+    >,
     planet_query: Query<&Planet>,
 ) {
     damage_lib::handle_collision_with_damageable(
@@ -207,12 +598,113 @@ pub fn handle_player_collision_with_planet(
     );
 }
 
+pub fn handle_player_collision_with_enemy(
+    mut commands: Commands,
+    mut player_ship_query: Query<
+        (
+            Entity,
+            &mut PlayerShip,
+            &CollidingEntities,
+            Option<&mut Shield>,
+        ),
+        Without<Invulnerable>,
+    >,
+    enemy_query: Query<&Enemy>,
+) {
+    damage_lib::handle_collision_with_damageable(
+        &mut commands,
+        &enemy_query,
+        &mut player_ship_query,
+    );
+}
+
+pub fn handle_player_collision_with_boss(
+    mut commands: Commands,
+    mut player_ship_query: Query<
+        (
+            Entity,
+            &mut PlayerShip,
+            &CollidingEntities,
+            Option<&mut Shield>,
+        ),
+        Without<Invulnerable>,
+    >,
+    boss_part_query: Query<&BossPart>,
+) {
+    damage_lib::handle_collision_with_damageable(
+        &mut commands,
+        &boss_part_query,
+        &mut player_ship_query,
+    );
+}
+
+/// Shot filters already keep the player's own faction from hitting it
+pub fn handle_player_hit_by_shots(
+    mut commands: Commands,
+    mut player_ship_query: Query<
+        (Entity, &mut PlayerShip, Option<&mut Shield>),
+        Without<Invulnerable>,
+    >,
+    shot_query: Query<(&Weapon, &ShapeHits)>,
+) {
+    let Ok((player_entity, mut player_ship, mut shield)) = player_ship_query.get_single_mut()
+    else {
+        return;
+    };
+    for (shot, hits) in shot_query.iter() {
+        if shots_systems::shot_hit(hits).is_some_and(|hit| hit.entity == player_entity) {
+            damage_lib::apply_damage(
+                &mut *player_ship,
+                shield.as_mut().map(|s| s.reborrow()),
+                shot,
+            );
+            if player_ship.is_dead() {
+                _despawn(&mut commands, player_entity);
+                return;
+            }
+        }
+    }
+}
+
+/// Explosions hurt the ship like any other hit, shield first, unless it's invulnerable
+pub fn handle_player_caught_in_explosions(
+    mut commands: Commands,
+    mut explosion_events: EventReader<Explosion>,
+    friendly_fire: Res<FriendlyFire>,
+    spatial_query: SpatialQuery,
+    mut player_ship_query: Query<
+        (
+            &mut PlayerShip,
+            &GlobalTransform,
+            Option<&mut Shield>,
+            Option<&mut LinearVelocity>,
+            Option<&Mass>,
+        ),
+        Without<Invulnerable>,
+    >,
+) {
+    let mut hit = Vec::new();
+    for explosion in explosion_events.read() {
+        hit.extend(damage_lib::apply_explosion(
+            explosion,
+            &friendly_fire,
+            &spatial_query,
+            &mut player_ship_query,
+        ));
+    }
+    hit.sort();
+    hit.dedup();
+    for entity in hit {
+        if let Ok((player_ship, ..)) = player_ship_query.get(entity) {
+            if player_ship.is_dead() {
+                _despawn(&mut commands, entity);
+            }
+        }
+    }
+}
+
 pub fn handle_player_respawn_on_death(
     mut commands: Commands,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    asset_server: Res<AssetServer>,
-    texture_atlases: ResMut<Assets<TextureAtlas>>,
-    sprite_loader: Res<XMLSpriteSheetLoader>,
     mut player_ship_query: Query<&PlayerShip>,
     mut player_lives: ResMut<PlayerLives>,
     mut app_state_next_state: ResMut<NextState<AppState>>,
@@ -221,13 +713,7 @@ pub fn handle_player_respawn_on_death(
         if player_ship.is_dead() {
             player_lives.lives -= 1;
             if player_lives.lives > 0 {
-                spawn_ship(
-                    commands,
-                    window_query,
-                    asset_server,
-                    texture_atlases,
-                    sprite_loader,
-                );
+                commands.init_resource::<PendingRespawn>();
             } else {
                 app_state_next_state.set(AppState::GameOver);
             }
@@ -235,6 +721,54 @@ pub fn handle_player_respawn_on_death(
     }
 }
 
+/// Brings in the next ship once there's somewhere clear of meteors and planets to put it,
+/// preferring the usual spawn point
+pub fn respawn_player_when_clear(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    part_registry: Res<ShipPartRegistry>,
+    loadout: Res<ShipLoadout>,
+    spatial_query: SpatialQuery,
+    meteor_query: Query<(), With<Meteor>>,
+    planet_query: Query<(), With<Planet>>,
+) {
+    let window = window_query.get_single().unwrap();
+    let preferred = spawn_position(window);
+    let position = if is_clear(
+        &spatial_query,
+        preferred,
+        PLAYER_SPAWN_CLEARANCE,
+        &meteor_query,
+        &planet_query,
+    ) {
+        Some(preferred)
+    } else {
+        find_safe_position(
+            &spatial_query,
+            window,
+            PLAYER_SPAWN_CLEARANCE,
+            &meteor_query,
+            &planet_query,
+        )
+    };
+    // Nowhere clear yet, try again next frame
+    if let Some(position) = position {
+        commands.remove_resource::<PendingRespawn>();
+        spawn_ship_at(
+            &mut commands,
+            &asset_server,
+            &mut texture_atlases,
+            &sprite_loader,
+            &part_registry,
+            &loadout,
+            position,
+        );
+    }
+}
+
 pub fn render_player_health(mut gizmos: Gizmos, player_query: Query<(&PlayerShip, &Transform)>) {
     if let Ok((player_ship, transform)) = player_query.get_single() {
         if !player_ship.is_dead() {
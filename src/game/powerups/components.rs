@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::thread_rng;
+
+use super::POWER_UP_LIFETIME;
+use crate::game::enemies::components::SaucerKind;
+use crate::game::meteors::components::MeteorType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerUpKind {
+    Shield,
+    ExtraLife,
+    Repair,
+    RapidFire,
+    WeaponUpgrade,
+}
+
+impl PowerUpKind {
+    pub fn random() -> Self {
+        let mut rng = thread_rng();
+        let items = [
+            (PowerUpKind::Repair, 4),
+            (PowerUpKind::Shield, 3),
+            (PowerUpKind::RapidFire, 3),
+            (PowerUpKind::WeaponUpgrade, 2),
+            (PowerUpKind::ExtraLife, 1),
+        ];
+        let dist = WeightedIndex::new(items.iter().map(|item| item.1)).unwrap();
+
+        items[dist.sample(&mut rng)].0
+    }
+
+    /// Chance of a destroyed meteor dropping a power up, bigger meteors are more generous
+    pub fn drop_chance(meteor_type: MeteorType) -> f32 {
+        match meteor_type {
+            MeteorType::Big => 0.3,
+            MeteorType::Med => 0.15,
+            MeteorType::Small => 0.05,
+        }
+    }
+
+    /// Chance of a shot down saucer dropping a power up, the harder small ones always do
+    pub fn enemy_drop_chance(kind: SaucerKind) -> f32 {
+        match kind {
+            SaucerKind::Big => 0.5,
+            SaucerKind::Small => 1.0,
+        }
+    }
+
+    pub fn sprite_name(&self) -> &'static str {
+        match self {
+            PowerUpKind::Shield => "powerupBlue_shield.png",
+            PowerUpKind::ExtraLife => "star_gold.png",
+            PowerUpKind::Repair => "pill_green.png",
+            PowerUpKind::RapidFire => "bolt_gold.png",
+            PowerUpKind::WeaponUpgrade => "powerupRed_bolt.png",
+        }
+    }
+}
+
+/// A pickup drifting through the play field, applied to the player ship on contact
+#[derive(Component)]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+    pub lifetime: Timer,
+}
+
+impl PowerUp {
+    pub fn new(kind: PowerUpKind) -> Self {
+        PowerUp {
+            kind,
+            lifetime: Timer::from_seconds(POWER_UP_LIFETIME, TimerMode::Once),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use systems::*;
+
+pub const POWER_UP_SCALE: f32 = 0.8;
+pub const POWER_UP_SPEED_RANGE: (f32, f32) = (-30.0, 30.0);
+pub const POWER_UP_ROTATION_RANGE: (f32, f32) = (-1.5, 1.5);
+pub const POWER_UP_LIFETIME: f32 = 12.0;
+// power ups blink for the last few seconds before they expire
+pub const POWER_UP_BLINK_TIME: f32 = 3.0;
+pub const POWER_UP_BLINK_INTERVAL: f32 = 0.2;
+
+pub const REPAIR_AMOUNT: f32 = 750.;
+pub const RAPID_FIRE_DELAY_SCALE: f32 = 0.75;
+
+pub struct PowerUpsPlugin;
+
+impl Plugin for PowerUpsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                drop_power_ups_from_meteors,
+                drop_power_ups_from_enemies,
+                drop_power_ups_from_bosses,
+                collect_power_ups,
+                expire_power_ups,
+                handle_power_up_intersections_with_wall,
+            )
+                .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+        )
+        .add_systems(OnExit(AppState::Game), despawn_power_ups);
+    }
+}
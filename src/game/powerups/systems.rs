@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy_xpbd_2d::prelude::*;
+use rand::{random, thread_rng, Rng};
+
+use super::components::*;
+use super::*;
+use crate::game::bosses::events::BossDestroyed;
+use crate::game::damage::components::Shield;
+use crate::game::enemies::events::EnemyDestroyed;
+use crate::game::meteors::events::MeteorDestroyed;
+use crate::game::player::components::{PlayerLives, PlayerShip};
+use crate::game::player::PLAYER_MAX_LIVES;
+use crate::game::shots::components::{WeaponFireTimer, WeaponUpgrade};
+use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
+use crate::game::world::systems as world_systems;
+
+pub fn despawn_power_ups(mut commands: Commands, power_up_query: Query<Entity, With<PowerUp>>) {
+    for entity in power_up_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+pub fn drop_power_ups_from_meteors(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    mut meteor_destroyed_events: EventReader<MeteorDestroyed>,
+) {
+    for event in meteor_destroyed_events.read() {
+        if random::<f32>() < PowerUpKind::drop_chance(event.meteor_type) {
+            spawn_power_up_at_position(
+                &mut commands,
+                &asset_server,
+                &mut texture_atlases,
+                &sprite_loader,
+                PowerUpKind::random(),
+                event.position,
+            );
+        }
+    }
+}
+
+pub fn drop_power_ups_from_enemies(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    mut enemy_destroyed_events: EventReader<EnemyDestroyed>,
+) {
+    for event in enemy_destroyed_events.read() {
+        if random::<f32>() < PowerUpKind::enemy_drop_chance(event.kind) {
+            spawn_power_up_at_position(
+                &mut commands,
+                &asset_server,
+                &mut texture_atlases,
+                &sprite_loader,
+                PowerUpKind::random(),
+                event.position,
+            );
+        }
+    }
+}
+
+/// Bosses always leave something behind
+pub fn drop_power_ups_from_bosses(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    mut boss_destroyed_events: EventReader<BossDestroyed>,
+) {
+    for event in boss_destroyed_events.read() {
+        spawn_power_up_at_position(
+            &mut commands,
+            &asset_server,
+            &mut texture_atlases,
+            &sprite_loader,
+            PowerUpKind::random(),
+            event.position,
+        );
+    }
+}
+
+fn spawn_power_up_at_position(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
+    sprite_loader: &Res<XMLSpriteSheetLoader>,
+    kind: PowerUpKind,
+    translation: Vec2,
+) {
+    let mut rng = thread_rng();
+    let sprite_name = kind.sprite_name();
+    let radius = sprite_loader.get_sprite(sprite_name).unwrap().half_width();
+    let velocity = Vec2::new(
+        rng.gen_range(POWER_UP_SPEED_RANGE.0..=POWER_UP_SPEED_RANGE.1),
+        rng.gen_range(POWER_UP_SPEED_RANGE.0..=POWER_UP_SPEED_RANGE.1),
+    );
+    let rotation = rng.gen_range(POWER_UP_ROTATION_RANGE.0..=POWER_UP_ROTATION_RANGE.1);
+    commands
+        .spawn((
+            world_systems::sprite_sheet_bundle(
+                asset_server,
+                texture_atlases,
+                sprite_loader,
+                sprite_name,
+                1,
+                1,
+                0,
+            ),
+            PowerUp::new(kind),
+        ))
+        .insert(
+            Transform::from_xyz(translation.x, translation.y, 0.)
+                .with_scale(Vec3::splat(POWER_UP_SCALE)),
+        )
+        .insert((
+            RigidBody::Kinematic,
+            Sensor,
+            Collider::ball(radius),
+            LinearVelocity(velocity),
+            AngularVelocity(rotation),
+        ));
+}
+
+pub fn collect_power_ups(
+    mut commands: Commands,
+    power_up_query: Query<(Entity, &PowerUp, &CollidingEntities)>,
+    mut player_ship_query: Query<(
+        Entity,
+        &mut PlayerShip,
+        &mut WeaponFireTimer,
+        Option<&mut Shield>,
+        Option<&mut WeaponUpgrade>,
+    )>,
+    mut player_lives: ResMut<PlayerLives>,
+) {
+    if let Ok((
+        player_entity,
+        mut player_ship,
+        mut weapon_fire_timer,
+        mut shield,
+        mut weapon_upgrade,
+    )) = player_ship_query.get_single_mut()
+    {
+        for (power_up_entity, power_up, colliding_entities) in power_up_query.iter() {
+            if !colliding_entities.contains(&player_entity) {
+                continue;
+            }
+            match power_up.kind {
+                PowerUpKind::Shield => match shield.as_mut() {
+                    Some(shield) => shield.refill(),
+                    None => {
+                        commands.entity(player_entity).insert(Shield::default());
+                    }
+                },
+                PowerUpKind::ExtraLife => {
+                    player_lives.lives = (player_lives.lives + 1).min(PLAYER_MAX_LIVES);
+                }
+                PowerUpKind::Repair => {
+                    player_ship.health =
+                        (player_ship.health + REPAIR_AMOUNT).min(player_ship.max_health);
+                }
+                PowerUpKind::RapidFire => {
+                    weapon_fire_timer.delay_scale *= RAPID_FIRE_DELAY_SCALE;
+                }
+                PowerUpKind::WeaponUpgrade => match weapon_upgrade.as_mut() {
+                    Some(weapon_upgrade) => weapon_upgrade.upgrade(),
+                    None => {
+                        let mut weapon_upgrade = WeaponUpgrade::default();
+                        weapon_upgrade.upgrade();
+                        commands.entity(player_entity).insert(weapon_upgrade);
+                    }
+                },
+            }
+            commands.entity(power_up_entity).despawn();
+        }
+    }
+}
+
+pub fn expire_power_ups(
+    mut commands: Commands,
+    mut power_up_query: Query<(Entity, &mut PowerUp, &mut Visibility)>,
+    time: Res<Time>,
+) {
+    for (entity, mut power_up, mut visibility) in power_up_query.iter_mut() {
+        if power_up.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let remaining = power_up.lifetime.remaining_secs();
+        if remaining < POWER_UP_BLINK_TIME {
+            let blink_on = (remaining / POWER_UP_BLINK_INTERVAL).floor() % 2. == 0.;
+            *visibility = if blink_on {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+}
+
+pub fn handle_power_up_intersections_with_wall(
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut power_up_query: Query<&mut Transform, With<PowerUp>>,
+) {
+    let window = window_query.get_single().unwrap();
+    for mut transform in power_up_query.iter_mut() {
+        let translation = transform.translation;
+        if translation.x < 0. {
+            transform.translation.x = window.width();
+        } else if translation.x > window.width() {
+            transform.translation.x = 0.;
+        }
+        if translation.y < 0. {
+            transform.translation.y = window.height();
+        } else if translation.y > window.height() {
+            transform.translation.y = 0.;
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+/// The radar panel, blips are spawned as its children
+#[derive(Component)]
+pub struct Radar;
+
+/// A blip on the radar tracking the position of `target` in the play field
+#[derive(Component)]
+pub struct RadarBlip {
+    pub target: Entity,
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod styles;
+mod systems;
+
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use systems::*;
+
+pub const RADAR_WIDTH: f32 = 180.;
+pub const RADAR_MARGIN: f32 = 10.;
+pub const PLAYER_BLIP_SIZE: f32 = 5.;
+
+pub struct RadarPlugin;
+
+impl Plugin for RadarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Game), spawn_radar)
+            .add_systems(
+                Update,
+                update_radar_blips
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            )
+            .add_systems(OnExit(AppState::Game), despawn_radar);
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use super::{RADAR_MARGIN, RADAR_WIDTH};
+
+pub const RADAR_BACKGROUND_COLOR: Color = Color::rgba(0.05, 0.05, 0.1, 0.6);
+pub const RADAR_BORDER_COLOR: Color = Color::rgba(0.35, 0.75, 0.35, 0.8);
+
+pub fn radar_style(aspect_ratio: f32) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        right: Val::Px(RADAR_MARGIN),
+        bottom: Val::Px(RADAR_MARGIN),
+        width: Val::Px(RADAR_WIDTH),
+        height: Val::Px(RADAR_WIDTH * aspect_ratio),
+        border: UiRect::all(Val::Px(1.)),
+        ..default()
+    }
+}
+
+/// Centres a blip of `size` pixels at `pos`, given as a fraction of the radar's width/height
+pub fn blip_style(pos: Vec2, size: f32) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Percent(pos.x * 100.),
+        top: Val::Percent(pos.y * 100.),
+        margin: UiRect {
+            left: Val::Px(-size / 2.),
+            top: Val::Px(-size / 2.),
+            ..default()
+        },
+        width: Val::Px(size),
+        height: Val::Px(size),
+        ..default()
+    }
+}
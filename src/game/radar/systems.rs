@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy::window::PrimaryWindow;
+
+use super::components::*;
+use super::styles::*;
+use super::{PLAYER_BLIP_SIZE, RADAR_WIDTH};
+use crate::game::meteors::components::{Meteor, MeteorType};
+use crate::game::planets::components::Planet;
+use crate::game::player::components::PlayerShip;
+
+pub fn spawn_radar(mut commands: Commands, window_query: Query<&Window, With<PrimaryWindow>>) {
+    let window = window_query.get_single().unwrap();
+    commands.spawn((
+        NodeBundle {
+            style: radar_style(window.height() / window.width()),
+            background_color: RADAR_BACKGROUND_COLOR.into(),
+            border_color: RADAR_BORDER_COLOR.into(),
+            ..default()
+        },
+        Radar {},
+    ));
+}
+
+pub fn despawn_radar(mut commands: Commands, radar_query: Query<Entity, With<Radar>>) {
+    if let Ok(radar_entity) = radar_query.get_single() {
+        commands.entity(radar_entity).despawn_recursive();
+    }
+}
+
+pub fn update_radar_blips(
+    mut commands: Commands,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    radar_query: Query<Entity, With<Radar>>,
+    tracked_query: Query<
+        (Entity, &Transform, Option<&Meteor>, Option<&Planet>),
+        Or<(With<Meteor>, With<Planet>, With<PlayerShip>)>,
+    >,
+    mut blip_query: Query<(Entity, &RadarBlip, &mut Style, &mut BackgroundColor)>,
+) {
+    let window = window_query.get_single().unwrap();
+    let Ok(radar_entity) = radar_query.get_single() else {
+        return;
+    };
+
+    let mut blips: HashMap<Entity, Entity> = HashMap::new();
+    for (blip_entity, blip, _, _) in blip_query.iter() {
+        if tracked_query.contains(blip.target) {
+            blips.insert(blip.target, blip_entity);
+        } else {
+            commands.entity(blip_entity).despawn_recursive();
+        }
+    }
+
+    for (entity, transform, meteor, planet) in tracked_query.iter() {
+        let pos = to_radar_position(transform.translation.truncate(), window);
+        let (color, size) = blip_appearance(meteor, planet, window.width());
+        match blips.get(&entity) {
+            Some(blip_entity) => {
+                if let Ok((_, _, mut style, mut background_color)) =
+                    blip_query.get_mut(*blip_entity)
+                {
+                    *style = blip_style(pos, size);
+                    *background_color = color.into();
+                }
+            }
+            None => {
+                let blip_entity = commands
+                    .spawn((
+                        NodeBundle {
+                            style: blip_style(pos, size),
+                            background_color: color.into(),
+                            ..default()
+                        },
+                        RadarBlip { target: entity },
+                    ))
+                    .id();
+                commands.entity(radar_entity).add_child(blip_entity);
+            }
+        }
+    }
+}
+
+/// Maps a world position to a fraction of the radar, with y flipped to match the UI's top-left origin
+fn to_radar_position(translation: Vec2, window: &Window) -> Vec2 {
+    Vec2::new(
+        translation.x / window.width(),
+        1. - translation.y / window.height(),
+    )
+    .clamp(Vec2::ZERO, Vec2::ONE)
+}
+
+fn blip_appearance(
+    meteor: Option<&Meteor>,
+    planet: Option<&Planet>,
+    field_width: f32,
+) -> (Color, f32) {
+    if let Some(meteor) = meteor {
+        meteor_blip_appearance(meteor.meteor_type())
+    } else if let Some(planet) = planet {
+        (planet.color, planet.radius * 2. * RADAR_WIDTH / field_width)
+    } else {
+        (Color::CYAN, PLAYER_BLIP_SIZE)
+    }
+}
+
+fn meteor_blip_appearance(meteor_type: MeteorType) -> (Color, f32) {
+    match meteor_type {
+        MeteorType::Big => (Color::ORANGE_RED, 6.),
+        MeteorType::Med => (Color::ORANGE, 4.),
+        MeteorType::Small => (Color::YELLOW, 3.),
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+mod components;
+pub mod resources;
+mod styles;
+mod systems;
+
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use resources::Score;
+use systems::*;
+
+pub const SCORE_MARGIN: f32 = 10.;
+pub const SCORE_FONT_SIZE: f32 = 28.;
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Score>()
+            .add_systems(OnEnter(AppState::Game), (reset_score, spawn_score_text))
+            .add_systems(
+                Update,
+                (
+                    award_points_for_meteors,
+                    award_points_for_enemies,
+                    award_points_for_bosses,
+                    update_score_text,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            )
+            .add_systems(OnExit(AppState::Game), despawn_score_text);
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use super::{SCORE_FONT_SIZE, SCORE_MARGIN};
+
+pub const SCORE_TEXT_COLOR: Color = Color::rgba(0.85, 0.85, 0.85, 0.9);
+
+pub fn score_style() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(SCORE_MARGIN),
+        top: Val::Px(SCORE_MARGIN),
+        ..default()
+    }
+}
+
+pub fn score_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+    TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: SCORE_FONT_SIZE,
+        color: SCORE_TEXT_COLOR,
+    }
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+
+use super::components::ScoreText;
+use super::resources::Score;
+use super::styles::*;
+use crate::game::bosses::events::BossDestroyed;
+use crate::game::enemies::events::EnemyDestroyed;
+use crate::game::meteors::events::MeteorDestroyed;
+
+pub fn reset_score(mut score: ResMut<Score>) {
+    score.value = 0;
+}
+
+pub fn spawn_score_text(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        TextBundle::from_section("0", score_text_style(&asset_server)).with_style(score_style()),
+        ScoreText,
+    ));
+}
+
+pub fn despawn_score_text(mut commands: Commands, text_query: Query<Entity, With<ScoreText>>) {
+    for entity in text_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn award_points_for_meteors(
+    mut score: ResMut<Score>,
+    mut meteor_destroyed_events: EventReader<MeteorDestroyed>,
+) {
+    for event in meteor_destroyed_events.read() {
+        score.value += event.meteor_type.points();
+    }
+}
+
+pub fn award_points_for_enemies(
+    mut score: ResMut<Score>,
+    mut enemy_destroyed_events: EventReader<EnemyDestroyed>,
+) {
+    for event in enemy_destroyed_events.read() {
+        score.value += event.kind.points();
+    }
+}
+
+pub fn award_points_for_bosses(
+    mut score: ResMut<Score>,
+    mut boss_destroyed_events: EventReader<BossDestroyed>,
+) {
+    for event in boss_destroyed_events.read() {
+        score.value += event.points;
+    }
+}
+
+pub fn update_score_text(score: Res<Score>, mut text_query: Query<&mut Text, With<ScoreText>>) {
+    if !score.is_changed() {
+        return;
+    }
+    for mut text in text_query.iter_mut() {
+        text.sections[0].value = score.value.to_string();
+    }
+}
@@ -1,16 +1,24 @@
+use crate::damage::components::Faction;
+use crate::damage::events::Explosion;
 use crate::damage::Damage;
 use bevy::prelude::*;
 use bevy::time::Stopwatch;
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::time::Duration;
 
+use super::resources::WeaponDefinition;
+
 pub const DEFAULT_WEAPON_FIRE_DELAY: Duration = Duration::from_millis(150);
-const DEFAULT_WEAPON_SPRITE_NAME: &str = "laserGreen02.png";
-const DEFAULT_WEAPON_DAMAGE: f32 = 25.;
-const DEFAULT_WEAPON_SPEED: f32 = 1500.;
-const DEFAULT_WEAPON_SCALE: f32 = 0.5;
 
 pub const FIRE_DISTANCE_FROM_PLAYER: f32 = 25.0;
-const SHOT_DENSITY: f32 = 0.001;
+pub const MIN_WEAPON_FIRE_DELAY: Duration = Duration::from_millis(50);
+pub const WEAPON_UPGRADE_MAX_LEVEL: u32 = 4;
+const WEAPON_UPGRADE_DAMAGE_PER_LEVEL: f32 = 0.25;
+pub const SHOT_DENSITY: f32 = 0.001;
+pub const SHIP_ENERGY_CAPACITY: f32 = 100.;
+pub const SHIP_ENERGY_REGEN_PER_SECOND: f32 = 20.;
+pub const BEAM_MAX_HITS: u32 = 16;
 
 #[derive(Component)]
 pub struct Weapon {
@@ -24,14 +32,14 @@ pub struct Weapon {
     pub start_frame: usize,
 }
 
-impl Default for Weapon {
-    fn default() -> Self {
+impl Weapon {
+    pub fn from_definition(definition: &WeaponDefinition) -> Self {
         Weapon {
-            sprite_name: DEFAULT_WEAPON_SPRITE_NAME.to_string(),
-            damage: DEFAULT_WEAPON_DAMAGE,
-            speed: DEFAULT_WEAPON_SPEED,
-            density: SHOT_DENSITY,
-            scale: DEFAULT_WEAPON_SCALE,
+            sprite_name: definition.sprite_name.clone(),
+            damage: definition.damage,
+            speed: definition.speed,
+            density: definition.density,
+            scale: definition.scale,
             frame_cols: 1,
             frame_rows: 1,
             start_frame: 0,
@@ -39,6 +47,12 @@ impl Default for Weapon {
     }
 }
 
+impl Default for Weapon {
+    fn default() -> Self {
+        Weapon::from_definition(&WeaponDefinition::default())
+    }
+}
+
 impl Damage for Weapon {
     fn hit_points(&self) -> f32 {
         self.damage
@@ -48,16 +62,242 @@ impl Damage for Weapon {
 #[derive(Component)]
 pub struct WeaponFireTimer {
     pub timer: Stopwatch,
-    pub fire_delay: Duration,
+    /// Multiplier on the equipped weapon's fire delay, lowered by rapid fire power ups
+    pub delay_scale: f32,
 }
 
 impl Default for WeaponFireTimer {
     fn default() -> Self {
         let mut timer = WeaponFireTimer {
             timer: Stopwatch::new(),
-            fire_delay: DEFAULT_WEAPON_FIRE_DELAY,
+            delay_scale: 1.,
         };
-        timer.timer.tick(timer.fire_delay);
+        timer.timer.tick(DEFAULT_WEAPON_FIRE_DELAY);
         timer
     }
 }
+
+impl WeaponFireTimer {
+    pub fn fire_delay(&self, base_delay: Duration) -> Duration {
+        base_delay
+            .mul_f32(self.delay_scale)
+            .max(MIN_WEAPON_FIRE_DELAY)
+    }
+
+    pub fn ready(&self, base_delay: Duration) -> bool {
+        self.timer.elapsed() >= self.fire_delay(base_delay)
+    }
+}
+
+/// Index into the `WeaponRegistry` of the weapon the ship currently fires
+#[derive(Component, Default)]
+pub struct EquippedWeapon {
+    pub index: usize,
+}
+
+impl EquippedWeapon {
+    pub fn next(&mut self, weapon_count: usize) {
+        self.index = (self.index + 1) % weapon_count;
+    }
+
+    pub fn previous(&mut self, weapon_count: usize) {
+        self.index = (self.index + weapon_count - 1) % weapon_count;
+    }
+}
+
+/// Shots despawn once their lifetime runs out or they've travelled their maximum distance
+#[derive(Component)]
+pub struct Projectile {
+    pub lifetime: Timer,
+    pub max_distance: Option<f32>,
+    pub travelled: f32,
+    /// Wrap around the field at the walls instead of despawning
+    pub wraps: bool,
+}
+
+impl Projectile {
+    pub fn from_definition(definition: &WeaponDefinition) -> Self {
+        Projectile {
+            lifetime: Timer::from_seconds(definition.lifetime, TimerMode::Once),
+            max_distance: definition.max_distance,
+            travelled: 0.,
+            wraps: definition.wraps,
+        }
+    }
+
+    pub fn travel(&mut self, distance: f32) {
+        self.travelled += distance;
+    }
+
+    pub fn expired(&self) -> bool {
+        self.lifetime.finished()
+            || self
+                .max_distance
+                .is_some_and(|max_distance| self.travelled >= max_distance)
+    }
+}
+
+/// Extra damage on every shot fired, picked up from power ups
+#[derive(Component, Default)]
+pub struct WeaponUpgrade {
+    pub level: u32,
+}
+
+impl WeaponUpgrade {
+    pub fn upgrade(&mut self) {
+        self.level = (self.level + 1).min(WEAPON_UPGRADE_MAX_LEVEL);
+    }
+
+    pub fn damage_multiplier(&self) -> f32 {
+        1. + self.level as f32 * WEAPON_UPGRADE_DAMAGE_PER_LEVEL
+    }
+}
+
+/// Where a ship's shots leave from, relative to its centre. Each volley is fired from every one
+#[derive(Component)]
+pub struct Hardpoints(pub Vec<Vec2>);
+
+impl Default for Hardpoints {
+    fn default() -> Self {
+        Hardpoints(vec![Vec2::new(0., FIRE_DISTANCE_FROM_PLAYER)])
+    }
+}
+
+/// Volleys still to fire from the current burst
+#[derive(Component)]
+pub struct BurstFire {
+    pub remaining: u32,
+    pub timer: Timer,
+}
+
+impl BurstFire {
+    pub fn new(remaining: u32, delay: Duration) -> Self {
+        BurstFire {
+            remaining,
+            timer: Timer::new(delay, TimerMode::Repeating),
+        }
+    }
+}
+
+/// How long fire has been held for a charged weapon
+#[derive(Component, Default)]
+pub struct ChargingShot {
+    pub held: Stopwatch,
+}
+
+/// Steers a shot towards the nearest target in a cone in front of it
+#[derive(Component, Debug, Clone, Deserialize)]
+pub struct Homing {
+    pub cone_degrees: f32,
+    /// Maximum turn in degrees per second
+    pub turn_rate_degrees: f32,
+    pub range: f32,
+    #[serde(skip)]
+    pub target: Option<Entity>,
+}
+
+impl Homing {
+    pub fn half_cone(&self) -> f32 {
+        self.cone_degrees.to_radians() / 2.
+    }
+
+    pub fn turn_rate(&self) -> f32 {
+        self.turn_rate_degrees.to_radians()
+    }
+}
+
+/// Blows up on impact, damaging everything within `radius` instead of only what it hit
+#[derive(Component, Debug, Clone, Deserialize)]
+pub struct Explosive {
+    pub radius: f32,
+    #[serde(default = "default_falloff")]
+    pub falloff: f32,
+    #[serde(default)]
+    pub impulse: f32,
+}
+
+fn default_falloff() -> f32 {
+    1.
+}
+
+impl Explosive {
+    pub fn explosion(&self, centre: Vec2, damage: f32, faction: Faction) -> Explosion {
+        Explosion {
+            centre,
+            radius: self.radius,
+            damage,
+            falloff: self.falloff,
+            impulse: self.impulse,
+            faction,
+        }
+    }
+}
+
+/// Ammo and heat of one weapon, kept while it isn't equipped so it reloads and cools down
+pub struct WeaponReserve {
+    pub ammo: u32,
+    pub reload: Option<Timer>,
+    pub heat: f32,
+    pub overheated: bool,
+}
+
+/// The ship's `WeaponReserve` for each weapon in the `WeaponRegistry` it has used, by index
+#[derive(Component, Default)]
+pub struct WeaponReserves {
+    reserves: HashMap<usize, WeaponReserve>,
+}
+
+impl WeaponReserves {
+    pub fn get_mut(&mut self, index: usize, definition: &WeaponDefinition) -> &mut WeaponReserve {
+        self.reserves.entry(index).or_insert_with(|| WeaponReserve {
+            ammo: definition
+                .resource
+                .as_ref()
+                .map_or(0, |resource| resource.capacity()),
+            reload: None,
+            heat: 0.,
+            overheated: false,
+        })
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&usize, &mut WeaponReserve)> {
+        self.reserves.iter_mut()
+    }
+}
+
+/// Shared pool drained by energy weapons, regenerating over time
+#[derive(Component)]
+pub struct ShipEnergy {
+    pub energy: f32,
+    pub capacity: f32,
+    pub regen_per_second: f32,
+}
+
+impl Default for ShipEnergy {
+    fn default() -> Self {
+        ShipEnergy {
+            energy: SHIP_ENERGY_CAPACITY,
+            capacity: SHIP_ENERGY_CAPACITY,
+            regen_per_second: SHIP_ENERGY_REGEN_PER_SECOND,
+        }
+    }
+}
+
+impl ShipEnergy {
+    pub fn regenerate(&mut self, delta: Duration) {
+        self.energy =
+            (self.energy + self.regen_per_second * delta.as_secs_f32()).min(self.capacity);
+    }
+
+    pub fn energy_pct(&self) -> f32 {
+        self.energy / self.capacity
+    }
+}
+
+/// The sprite stretched from the ship's nose to whatever the beam hits
+#[derive(Component)]
+pub struct BeamSegment;
+
+/// On screen readout of the equipped weapon and what it has left
+#[derive(Component)]
+pub struct WeaponHud;
@@ -1,21 +1,44 @@
 use bevy::prelude::*;
 
 pub mod components;
-mod systems;
+pub mod resources;
+mod styles;
+pub mod systems;
 
 use crate::game::states::SimulationState;
 use crate::states::AppState;
+use resources::WeaponRegistry;
 use systems::*;
 
+pub const WEAPONS_FILE: &str = "weapons/weapons.json";
+pub const WEAPON_HUD_MARGIN: f32 = 10.;
+pub const WEAPON_HUD_FONT_SIZE: f32 = 20.;
+
 pub struct WeaponFirePlugin;
 
 impl Plugin for WeaponFirePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (player_fire_weapon, handle_shot_intersections_with_wall)
-                .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
-        )
-        .add_systems(OnExit(AppState::Game), despawn_weapons);
+        app.insert_resource(WeaponRegistry::build(WEAPONS_FILE).unwrap())
+            .add_systems(OnEnter(AppState::Game), spawn_weapon_hud)
+            .add_systems(
+                Update,
+                (
+                    cycle_equipped_weapon,
+                    player_fire_weapon,
+                    fire_burst_volleys,
+                    fire_beam,
+                    replenish_weapon_reserves,
+                    steer_homing_projectiles,
+                    expire_projectiles,
+                    handle_shot_hits,
+                    handle_shot_intersections_with_wall,
+                    update_weapon_hud,
+                )
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            )
+            .add_systems(
+                OnExit(AppState::Game),
+                (despawn_weapons, despawn_weapon_hud),
+            );
     }
 }
@@ -0,0 +1,407 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::fs;
+use std::time::Duration;
+
+use super::components::{
+    Explosive, Homing, ShipEnergy, WeaponReserve, DEFAULT_WEAPON_FIRE_DELAY,
+    FIRE_DISTANCE_FROM_PLAYER, SHOT_DENSITY,
+};
+
+const DEFAULT_WEAPON_NAME: &str = "Green Laser";
+const DEFAULT_WEAPON_SPRITE_NAME: &str = "laserGreen02.png";
+const DEFAULT_WEAPON_DAMAGE: f32 = 25.;
+const DEFAULT_WEAPON_SPEED: f32 = 1500.;
+const DEFAULT_WEAPON_SCALE: f32 = 0.5;
+const DEFAULT_WEAPON_LIFETIME: f32 = 1.5;
+
+/// How many shots a weapon fires at once and where they go
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(tag = "type")]
+pub enum FirePattern {
+    #[default]
+    Single,
+    /// `count` shots fanned out evenly across `angle_degrees`
+    Spread { count: usize, angle_degrees: f32 },
+    /// One straight shot from each offset relative to the ship's centre
+    Cannons { offsets: Vec<Vec2> },
+}
+
+impl FirePattern {
+    /// Offset from the ship and angle in radians relative to where the ship is facing of each
+    /// shot in a volley
+    pub fn shots(&self) -> Vec<(Vec3, f32)> {
+        let nose = Vec3::new(0., FIRE_DISTANCE_FROM_PLAYER, 0.);
+        match self {
+            FirePattern::Single => vec![(nose, 0.)],
+            FirePattern::Spread {
+                count,
+                angle_degrees,
+            } => {
+                if *count <= 1 {
+                    return vec![(nose, 0.)];
+                }
+                let spread = angle_degrees.to_radians();
+                let step = spread / (*count - 1) as f32;
+                (0..*count)
+                    .map(|i| (nose, -spread / 2. + step * i as f32))
+                    .collect()
+            }
+            FirePattern::Cannons { offsets } => offsets
+                .iter()
+                .map(|offset| (offset.extend(0.), 0.))
+                .collect(),
+        }
+    }
+}
+
+/// Fires the weapon's pattern `count` times per trigger pull
+#[derive(Debug, Clone, Deserialize)]
+pub struct Burst {
+    pub count: u32,
+    #[serde(rename = "delay_ms", deserialize_with = "deserialize_millis")]
+    pub delay: Duration,
+}
+
+/// Holding fire charges the shot up, releasing fires it with scaled damage and size
+#[derive(Debug, Clone, Deserialize)]
+pub struct Charge {
+    #[serde(rename = "max_secs")]
+    pub max_time: f32,
+    pub max_damage_scale: f32,
+    pub max_size_scale: f32,
+}
+
+impl Charge {
+    /// Damage and size multipliers after holding fire for `held` seconds
+    pub fn scale(&self, held: f32) -> (f32, f32) {
+        let charged = if self.max_time > 0. {
+            (held / self.max_time).clamp(0., 1.)
+        } else {
+            1.
+        };
+        (
+            1. + (self.max_damage_scale - 1.) * charged,
+            1. + (self.max_size_scale - 1.) * charged,
+        )
+    }
+}
+
+/// Fires a continuous ray while held instead of shots, the weapon's damage and resource costs
+/// are per second
+#[derive(Debug, Clone, Deserialize)]
+pub struct Beam {
+    pub range: f32,
+}
+
+/// What a weapon uses up when it fires, weapons without one fire for free
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type")]
+pub enum WeaponResource {
+    /// `capacity` volleys, reloading automatically once empty
+    Ammo {
+        capacity: u32,
+        #[serde(rename = "reload_secs")]
+        reload_time: f32,
+    },
+    /// Fills a heat meter by `per_shot`, the weapon locks once it's full until it cools right down
+    Heat {
+        per_shot: f32,
+        cooling_per_second: f32,
+    },
+    /// Drains the ship's shared `ShipEnergy`
+    Energy { per_shot: f32 },
+}
+
+impl WeaponResource {
+    /// Catches resources that would leave the weapon unable to fire or recover
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            WeaponResource::Ammo { capacity: 0, .. } => Err("ammo capacity of 0".to_string()),
+            WeaponResource::Ammo { reload_time, .. } if *reload_time <= 0. => {
+                Err("reload_secs is not positive".to_string())
+            }
+            WeaponResource::Heat {
+                cooling_per_second, ..
+            } if *cooling_per_second <= 0. => Err("cooling_per_second is not positive".to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn capacity(&self) -> u32 {
+        match self {
+            WeaponResource::Ammo { capacity, .. } => *capacity,
+            _ => 0,
+        }
+    }
+
+    /// `scale` is the fraction of a shot's cost being paid, the frame time for beams
+    pub fn can_fire(&self, reserve: &WeaponReserve, energy: &ShipEnergy, scale: f32) -> bool {
+        match self {
+            WeaponResource::Ammo { .. } => reserve.ammo > 0,
+            WeaponResource::Heat { .. } => !reserve.overheated,
+            WeaponResource::Energy { per_shot } => energy.energy >= per_shot * scale,
+        }
+    }
+
+    pub fn consume(&self, reserve: &mut WeaponReserve, energy: &mut ShipEnergy, scale: f32) {
+        match self {
+            WeaponResource::Ammo { reload_time, .. } => {
+                reserve.ammo = reserve.ammo.saturating_sub(1);
+                if reserve.ammo == 0 && reserve.reload.is_none() {
+                    reserve.reload = Some(Timer::from_seconds(*reload_time, TimerMode::Once));
+                }
+            }
+            WeaponResource::Heat { per_shot, .. } => {
+                reserve.heat = (reserve.heat + per_shot * scale).min(1.);
+                if reserve.heat >= 1. {
+                    reserve.overheated = true;
+                }
+            }
+            WeaponResource::Energy { per_shot } => {
+                energy.energy = (energy.energy - per_shot * scale).max(0.);
+            }
+        }
+    }
+
+    /// Reloads or cools `reserve` down over `delta`
+    pub fn replenish(&self, reserve: &mut WeaponReserve, delta: Duration) {
+        match self {
+            WeaponResource::Ammo { capacity, .. } => {
+                if let Some(reload) = reserve.reload.as_mut() {
+                    if reload.tick(delta).finished() {
+                        reserve.ammo = *capacity;
+                        reserve.reload = None;
+                    }
+                }
+            }
+            WeaponResource::Heat {
+                cooling_per_second, ..
+            } => {
+                reserve.heat = (reserve.heat - cooling_per_second * delta.as_secs_f32()).max(0.);
+                if reserve.heat <= 0. {
+                    reserve.overheated = false;
+                }
+            }
+            WeaponResource::Energy { .. } => {}
+        }
+    }
+}
+
+/// A weapon the ship can equip, loaded from `WEAPONS_FILE`
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeaponDefinition {
+    pub name: String,
+    pub sprite_name: String,
+    pub damage: f32,
+    pub speed: f32,
+    #[serde(rename = "fire_delay_ms", deserialize_with = "deserialize_millis")]
+    pub fire_delay: Duration,
+    #[serde(rename = "lifetime_secs")]
+    pub lifetime: f32,
+    #[serde(default)]
+    pub max_distance: Option<f32>,
+    #[serde(default)]
+    pub wraps: bool,
+    pub scale: f32,
+    #[serde(default = "default_density")]
+    pub density: f32,
+    #[serde(default)]
+    pub pattern: FirePattern,
+    #[serde(default)]
+    pub burst: Option<Burst>,
+    #[serde(default)]
+    pub charge: Option<Charge>,
+    #[serde(default)]
+    pub homing: Option<Homing>,
+    #[serde(default)]
+    pub explosion: Option<Explosive>,
+    #[serde(default)]
+    pub beam: Option<Beam>,
+    #[serde(default)]
+    pub resource: Option<WeaponResource>,
+}
+
+fn deserialize_millis<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Duration, D::Error> {
+    Ok(Duration::from_millis(u64::deserialize(deserializer)?))
+}
+
+fn default_density() -> f32 {
+    SHOT_DENSITY
+}
+
+impl WeaponDefinition {
+    pub fn can_fire(&self, reserve: &WeaponReserve, energy: &ShipEnergy, scale: f32) -> bool {
+        match &self.resource {
+            Some(resource) => resource.can_fire(reserve, energy, scale),
+            None => true,
+        }
+    }
+
+    pub fn consume(&self, reserve: &mut WeaponReserve, energy: &mut ShipEnergy, scale: f32) {
+        if let Some(resource) = &self.resource {
+            resource.consume(reserve, energy, scale);
+        }
+    }
+}
+
+impl Default for WeaponDefinition {
+    fn default() -> Self {
+        WeaponDefinition {
+            name: DEFAULT_WEAPON_NAME.to_string(),
+            sprite_name: DEFAULT_WEAPON_SPRITE_NAME.to_string(),
+            damage: DEFAULT_WEAPON_DAMAGE,
+            speed: DEFAULT_WEAPON_SPEED,
+            fire_delay: DEFAULT_WEAPON_FIRE_DELAY,
+            lifetime: DEFAULT_WEAPON_LIFETIME,
+            max_distance: None,
+            wraps: false,
+            scale: DEFAULT_WEAPON_SCALE,
+            density: SHOT_DENSITY,
+            pattern: FirePattern::Single,
+            burst: None,
+            charge: None,
+            homing: None,
+            explosion: None,
+            beam: None,
+            resource: None,
+        }
+    }
+}
+
+/// Every weapon the ship can cycle through, in order
+#[derive(Resource)]
+pub struct WeaponRegistry {
+    weapons: Vec<WeaponDefinition>,
+}
+
+impl WeaponRegistry {
+    pub fn build(weapons_file: &str) -> Result<WeaponRegistry, std::io::Error> {
+        let weapons_file = format!("assets/{weapons_file}");
+        let json_string = fs::read_to_string(weapons_file)?;
+        let weapons: Vec<WeaponDefinition> = serde_json::from_str(&json_string)?;
+        for weapon in weapons.iter() {
+            if let Some(resource) = &weapon.resource {
+                resource.validate().map_err(|error| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("{}: {error}", weapon.name),
+                    )
+                })?;
+            }
+        }
+        if weapons.is_empty() {
+            return Ok(WeaponRegistry {
+                weapons: vec![WeaponDefinition::default()],
+            });
+        }
+        Ok(WeaponRegistry { weapons })
+    }
+
+    pub fn get(&self, index: usize) -> &WeaponDefinition {
+        &self.weapons[index % self.weapons.len()]
+    }
+
+    pub fn count(&self) -> usize {
+        self.weapons.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::shots::components::{Projectile, WeaponReserves};
+    use crate::game::shots::WEAPONS_FILE;
+
+    #[test]
+    fn test_build_weapon_registry() {
+        let registry = WeaponRegistry::build(WEAPONS_FILE).unwrap();
+        assert!(registry.count() > 1);
+        assert_eq!(registry.get(0).name, WeaponDefinition::default().name);
+        assert_eq!(registry.get(registry.count()).name, registry.get(0).name);
+    }
+
+    #[test]
+    fn test_weapon_resources_that_never_recover_are_rejected() {
+        let no_ammo = WeaponResource::Ammo {
+            capacity: 0,
+            reload_time: 2.,
+        };
+        let no_reload = WeaponResource::Ammo {
+            capacity: 6,
+            reload_time: 0.,
+        };
+        let no_cooling = WeaponResource::Heat {
+            per_shot: 0.1,
+            cooling_per_second: 0.,
+        };
+        assert!(no_ammo.validate().is_err());
+        assert!(no_reload.validate().is_err());
+        assert!(no_cooling.validate().is_err());
+        assert!(WeaponResource::Energy { per_shot: 10. }.validate().is_ok());
+    }
+
+    #[test]
+    fn test_spread_shot_angles() {
+        let angles: Vec<f32> = FirePattern::Spread {
+            count: 3,
+            angle_degrees: 90.,
+        }
+        .shots()
+        .iter()
+        .map(|(_, angle)| *angle)
+        .collect();
+        assert_eq!(angles.len(), 3);
+        assert!((angles[0] + 45f32.to_radians()).abs() < 1e-6);
+        assert!(angles[1].abs() < 1e-6);
+        assert!((angles[2] - 45f32.to_radians()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_charge_scale_is_capped() {
+        let charge = Charge {
+            max_time: 2.,
+            max_damage_scale: 3.,
+            max_size_scale: 2.,
+        };
+        assert_eq!(charge.scale(0.), (1., 1.));
+        assert_eq!(charge.scale(1.), (2., 1.5));
+        assert_eq!(charge.scale(10.), (3., 2.));
+    }
+
+    #[test]
+    fn test_projectile_expires_after_max_distance() {
+        let definition = WeaponDefinition {
+            max_distance: Some(100.),
+            ..default()
+        };
+        let mut projectile = Projectile::from_definition(&definition);
+        projectile.travel(60.);
+        assert!(!projectile.expired());
+        projectile.travel(60.);
+        assert!(projectile.expired());
+    }
+
+    #[test]
+    fn test_ammo_reloads_once_empty() {
+        let definition = WeaponDefinition {
+            resource: Some(WeaponResource::Ammo {
+                capacity: 2,
+                reload_time: 1.,
+            }),
+            ..default()
+        };
+        let mut reserves = WeaponReserves::default();
+        let mut energy = ShipEnergy::default();
+        let reserve = reserves.get_mut(0, &definition);
+        definition.consume(reserve, &mut energy, 1.);
+        definition.consume(reserve, &mut energy, 1.);
+        assert!(!definition.can_fire(reserve, &energy, 1.));
+        let resource = definition.resource.as_ref().unwrap();
+        resource.replenish(reserve, Duration::from_secs(1));
+        assert_eq!(reserve.ammo, 2);
+        assert!(definition.can_fire(reserve, &energy, 1.));
+    }
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use super::{WEAPON_HUD_FONT_SIZE, WEAPON_HUD_MARGIN};
+
+pub const WEAPON_HUD_TEXT_COLOR: Color = Color::rgba(0.85, 0.85, 0.85, 0.9);
+pub const WEAPON_HUD_WARNING_COLOR: Color = Color::ORANGE_RED;
+
+pub fn weapon_hud_style() -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(WEAPON_HUD_MARGIN),
+        bottom: Val::Px(WEAPON_HUD_MARGIN),
+        ..default()
+    }
+}
+
+pub fn weapon_hud_text_style(asset_server: &Res<AssetServer>) -> TextStyle {
+    TextStyle {
+        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+        font_size: WEAPON_HUD_FONT_SIZE,
+        color: WEAPON_HUD_TEXT_COLOR,
+    }
+}
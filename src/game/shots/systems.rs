@@ -1,13 +1,30 @@
 use bevy::prelude::*;
 use bevy_xpbd_2d::math::Vector;
 use bevy_xpbd_2d::prelude::*;
+use std::f32::consts::FRAC_PI_2;
 
+use crate::game::bosses::components::BossPart;
+use crate::game::controls::resources::{ActionState, InputAction};
+use crate::game::damage::components::Faction;
+use crate::game::damage::events::Explosion;
+use crate::game::damage::resources::FriendlyFire;
+use crate::game::damage::{Damageable, HitPoints};
+use crate::game::enemies::components::Enemy;
+use crate::game::meteors::components::Meteor;
+use crate::game::planets::components::Planet;
 use crate::game::player::components::PlayerShip;
 use crate::game::shots::components::*;
+use crate::game::shots::resources::{WeaponDefinition, WeaponRegistry, WeaponResource};
+use crate::game::shots::styles::*;
 use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
+use crate::game::util;
 use crate::game::world::components::{BottomWall, LeftWall, RightWall, TopWall};
+use crate::game::world::systems as world_systems;
 
-pub fn despawn_weapons(mut commands: Commands, shot_query: Query<Entity, With<Weapon>>) {
+pub fn despawn_weapons(
+    mut commands: Commands,
+    shot_query: Query<Entity, Or<(With<Weapon>, With<BeamSegment>)>>,
+) {
     for entity in shot_query.iter() {
         commands.entity(entity).despawn();
     }
@@ -17,50 +34,478 @@ pub fn player_fire_weapon(
     asset_server: Res<AssetServer>,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
     sprite_loader: Res<XMLSpriteSheetLoader>,
-    keyboard_input: Res<Input<KeyCode>>,
-    mouse_input: Res<Input<MouseButton>>,
-    mut player_ship_query: Query<(Entity, &Transform, &mut WeaponFireTimer), With<PlayerShip>>,
+    action_state: Res<ActionState>,
+    weapon_registry: Res<WeaponRegistry>,
+    friendly_fire: Res<FriendlyFire>,
+    mut player_ship_query: Query<
+        (
+            Entity,
+            &Faction,
+            &Transform,
+            &Hardpoints,
+            &mut WeaponFireTimer,
+            &EquippedWeapon,
+            &mut WeaponReserves,
+            &mut ShipEnergy,
+            Option<&WeaponUpgrade>,
+            Option<&mut ChargingShot>,
+        ),
+        (With<PlayerShip>, Without<BurstFire>),
+    >,
     time: Res<Time>,
 ) {
-    if let Ok((entity, transform, mut weapon_fire_timer)) = player_ship_query.get_single_mut() {
+    if let Ok((
+        entity,
+        faction,
+        transform,
+        hardpoints,
+        mut weapon_fire_timer,
+        equipped_weapon,
+        mut weapon_reserves,
+        mut ship_energy,
+        weapon_upgrade,
+        charging_shot,
+    )) = player_ship_query.get_single_mut()
+    {
         weapon_fire_timer.timer.tick(time.delta());
-        let weapon = Weapon::default();
+        let definition = weapon_registry.get(equipped_weapon.index);
+        if definition.beam.is_some() {
+            return;
+        }
+        let reserve = weapon_reserves.get_mut(equipped_weapon.index, definition);
+        let damage_multiplier = weapon_upgrade.map_or(1., |upgrade| upgrade.damage_multiplier());
+        if let Some(charge) = &definition.charge {
+            match charging_shot {
+                Some(mut charging_shot) => {
+                    charging_shot.held.tick(time.delta());
+                    if action_state.pressed(InputAction::Fire) {
+                        return;
+                    }
+                    let (damage_scale, size_scale) =
+                        charge.scale(charging_shot.held.elapsed_secs());
+                    commands.entity(entity).remove::<ChargingShot>();
+                    weapon_fire_timer.timer.reset();
+                    definition.consume(reserve, &mut ship_energy, 1.);
+                    fire_volley(
+                        &mut commands,
+                        &asset_server,
+                        &mut texture_atlases,
+                        &sprite_loader,
+                        definition,
+                        damage_multiplier * damage_scale,
+                        size_scale,
+                        (entity, *faction),
+                        &friendly_fire,
+                        transform,
+                        hardpoints,
+                    );
+                }
+                None => {
+                    if action_state.pressed(InputAction::Fire)
+                        && weapon_fire_timer.ready(definition.fire_delay)
+                        && definition.can_fire(reserve, &ship_energy, 1.)
+                    {
+                        commands.entity(entity).insert(ChargingShot::default());
+                    }
+                }
+            }
+            return;
+        }
+        if action_state.pressed(InputAction::Fire)
+            && weapon_fire_timer.ready(definition.fire_delay)
+            && definition.can_fire(reserve, &ship_energy, 1.)
+        {
+            weapon_fire_timer.timer.reset();
+            definition.consume(reserve, &mut ship_energy, 1.);
+            fire_volley(
+                &mut commands,
+                &asset_server,
+                &mut texture_atlases,
+                &sprite_loader,
+                definition,
+                damage_multiplier,
+                1.,
+                (entity, *faction),
+                &friendly_fire,
+                transform,
+                hardpoints,
+            );
+            if let Some(burst) = &definition.burst {
+                if burst.count > 1 {
+                    commands
+                        .entity(entity)
+                        .insert(BurstFire::new(burst.count - 1, burst.delay));
+                }
+            }
+        }
+    }
+}
+
+pub fn fire_burst_volleys(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    weapon_registry: Res<WeaponRegistry>,
+    friendly_fire: Res<FriendlyFire>,
+    mut player_ship_query: Query<
+        (
+            Entity,
+            &Faction,
+            &Transform,
+            &Hardpoints,
+            &mut WeaponFireTimer,
+            &EquippedWeapon,
+            &mut WeaponReserves,
+            &mut ShipEnergy,
+            Option<&WeaponUpgrade>,
+            &mut BurstFire,
+        ),
+        With<PlayerShip>,
+    >,
+    time: Res<Time>,
+) {
+    for (
+        entity,
+        faction,
+        transform,
+        hardpoints,
+        mut weapon_fire_timer,
+        equipped_weapon,
+        mut weapon_reserves,
+        mut ship_energy,
+        weapon_upgrade,
+        mut burst_fire,
+    ) in player_ship_query.iter_mut()
+    {
+        if !burst_fire.timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+        let definition = weapon_registry.get(equipped_weapon.index);
+        let reserve = weapon_reserves.get_mut(equipped_weapon.index, definition);
+        if definition.can_fire(reserve, &ship_energy, 1.) {
+            definition.consume(reserve, &mut ship_energy, 1.);
+            fire_volley(
+                &mut commands,
+                &asset_server,
+                &mut texture_atlases,
+                &sprite_loader,
+                definition,
+                weapon_upgrade.map_or(1., |upgrade| upgrade.damage_multiplier()),
+                1.,
+                (entity, *faction),
+                &friendly_fire,
+                transform,
+                hardpoints,
+            );
+            burst_fire.remaining = burst_fire.remaining.saturating_sub(1);
+        } else {
+            burst_fire.remaining = 0;
+        }
+        if burst_fire.remaining == 0 {
+            // the fire delay counts from the last shot of the burst
+            weapon_fire_timer.timer.reset();
+            commands.entity(entity).remove::<BurstFire>();
+        }
+    }
+}
+
+/// Fires one shot for every entry in the weapon's fire pattern, from every hardpoint
+fn fire_volley(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
+    sprite_loader: &Res<XMLSpriteSheetLoader>,
+    definition: &WeaponDefinition,
+    damage_multiplier: f32,
+    size_scale: f32,
+    shooter: (Entity, Faction),
+    friendly_fire: &FriendlyFire,
+    ship_transform: &Transform,
+    hardpoints: &Hardpoints,
+) {
+    let nose = Vec3::new(0., FIRE_DISTANCE_FROM_PLAYER, 0.);
+    let shots = hardpoints.0.iter().flat_map(|hardpoint| {
+        // patterns are laid out around the nose, move them onto each hardpoint
+        let shift = hardpoint.extend(0.) - nose;
+        definition
+            .pattern
+            .shots()
+            .into_iter()
+            .map(move |(offset, angle)| (offset + shift, angle))
+    });
+    for (offset, angle) in shots {
+        let mut weapon = Weapon::from_definition(definition);
+        weapon.damage *= damage_multiplier;
+        weapon.scale *= size_scale;
         let sprite_name = weapon.sprite_name.clone();
-        if keyboard_input.pressed(KeyCode::Space)
-            || keyboard_input.just_pressed(KeyCode::Space)
-            || mouse_input.pressed(MouseButton::Left)
-            || mouse_input.just_pressed(MouseButton::Left)
+        let mut shot_transform = shot_from_transform(offset, ship_transform);
+        shot_transform.rotate_z(angle);
+        let rotation = shot_transform.rotation.to_scaled_axis();
+        let linvel = Vec2::from_angle(rotation.z).rotate(Vec2::Y) * weapon.speed;
+        let shot_entity = spawn_weapon_at_position(
+            commands,
+            asset_server,
+            texture_atlases,
+            sprite_loader,
+            &sprite_name,
+            weapon,
+            Projectile::from_definition(definition),
+            shooter,
+            friendly_fire,
+            shot_transform,
+            LinearVelocity(linvel),
+        );
+        if let Some(homing) = &definition.homing {
+            commands.entity(shot_entity).insert(homing.clone());
+        }
+        if let Some(explosive) = &definition.explosion {
+            commands.entity(shot_entity).insert(explosive.clone());
+        }
+    }
+}
+
+pub fn fire_beam(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    action_state: Res<ActionState>,
+    weapon_registry: Res<WeaponRegistry>,
+    spatial_query: SpatialQuery,
+    friendly_fire: Res<FriendlyFire>,
+    mut player_ship_query: Query<
+        (
+            Entity,
+            &Faction,
+            &Transform,
+            &EquippedWeapon,
+            &mut WeaponReserves,
+            &mut ShipEnergy,
+            Option<&WeaponUpgrade>,
+        ),
+        With<PlayerShip>,
+    >,
+    mut beam_query: Query<(Entity, &mut Transform), (With<BeamSegment>, Without<PlayerShip>)>,
+    mut meteor_query: Query<&mut Meteor>,
+    mut enemy_query: Query<&mut Enemy>,
+    mut boss_part_query: Query<&mut BossPart>,
+    planet_query: Query<(), With<Planet>>,
+    time: Res<Time>,
+) {
+    let Ok((
+        ship_entity,
+        faction,
+        ship_transform,
+        equipped_weapon,
+        mut weapon_reserves,
+        mut ship_energy,
+        weapon_upgrade,
+    )) = player_ship_query.get_single_mut()
+    else {
+        return;
+    };
+    let definition = weapon_registry.get(equipped_weapon.index);
+    let reserve = weapon_reserves.get_mut(equipped_weapon.index, definition);
+    let cost = time.delta_seconds();
+    let beam = match &definition.beam {
+        Some(beam)
+            if action_state.pressed(InputAction::Fire)
+                && definition.can_fire(reserve, &ship_energy, cost) =>
         {
-            if weapon_fire_timer.timer.elapsed() >= weapon_fire_timer.fire_delay {
-                weapon_fire_timer.timer.reset();
-                let rotation = transform.rotation.to_scaled_axis();
-                let linvel = Vec2::from_angle(rotation.z).rotate(Vec2::Y) * weapon.speed;
-                spawn_weapon_at_position(
-                    &mut commands,
-                    &asset_server,
-                    &mut texture_atlases,
-                    &sprite_loader,
-                    &sprite_name,
-                    weapon,
-                    entity,
-                    transform,
-                    LinearVelocity(linvel),
-                );
+            beam
+        }
+        _ => {
+            for (beam_entity, _) in beam_query.iter() {
+                commands.entity(beam_entity).despawn();
+            }
+            return;
+        }
+    };
+    definition.consume(reserve, &mut ship_energy, cost);
+
+    let nose = shot_from_transform(Vec3::new(0., FIRE_DISTANCE_FROM_PLAYER, 0.), ship_transform);
+    let origin = nose.translation.truncate();
+    let direction = (ship_transform.rotation * Vec3::Y).truncate();
+    let mut hits = spatial_query.ray_hits(
+        origin,
+        direction,
+        beam.range,
+        BEAM_MAX_HITS,
+        true,
+        faction
+            .shot_filter(&friendly_fire)
+            .without_entities([ship_entity]),
+    );
+    hits.sort_by(|a, b| a.time_of_impact.total_cmp(&b.time_of_impact));
+    // walls and pickups are sensors the beam passes straight through
+    let hit = hits.iter().find(|hit| {
+        meteor_query.contains(hit.entity)
+            || enemy_query.contains(hit.entity)
+            || boss_part_query.contains(hit.entity)
+            || planet_query.contains(hit.entity)
+    });
+    let length = hit.map_or(beam.range, |hit| hit.time_of_impact);
+    if let Some(hit) = hit {
+        let damage_multiplier = weapon_upgrade.map_or(1., |upgrade| upgrade.damage_multiplier());
+        let damage = HitPoints(definition.damage * damage_multiplier * time.delta_seconds());
+        if let Ok(mut meteor) = meteor_query.get_mut(hit.entity) {
+            meteor.damage(&damage);
+        } else if let Ok(mut enemy) = enemy_query.get_mut(hit.entity) {
+            enemy.damage(&damage);
+        } else if let Ok(mut boss_part) = boss_part_query.get_mut(hit.entity) {
+            boss_part.damage(&damage);
+        }
+    }
+
+    let sprite_height = sprite_loader
+        .get_sprite(&definition.sprite_name)
+        .map_or(1., |sprite| sprite.height);
+    let beam_transform = Transform {
+        translation: (origin + direction * length / 2.).extend(0.),
+        rotation: ship_transform.rotation,
+        scale: Vec3::new(definition.scale, length / sprite_height, 1.),
+    };
+    match beam_query.get_single_mut() {
+        Ok((_, mut transform)) => *transform = beam_transform,
+        Err(_) => {
+            let mut bundle = world_systems::sprite_sheet_bundle(
+                &asset_server,
+                &mut texture_atlases,
+                &sprite_loader,
+                &definition.sprite_name,
+                1,
+                1,
+                0,
+            );
+            bundle.transform = beam_transform;
+            commands.spawn((bundle, BeamSegment));
+        }
+    }
+}
+
+pub fn replenish_weapon_reserves(
+    weapon_registry: Res<WeaponRegistry>,
+    mut player_ship_query: Query<(&mut WeaponReserves, &mut ShipEnergy), With<PlayerShip>>,
+    time: Res<Time>,
+) {
+    for (mut weapon_reserves, mut ship_energy) in player_ship_query.iter_mut() {
+        ship_energy.regenerate(time.delta());
+        for (index, reserve) in weapon_reserves.iter_mut() {
+            if let Some(resource) = &weapon_registry.get(*index).resource {
+                resource.replenish(reserve, time.delta());
             }
         }
     }
 }
-fn spawn_weapon_at_position(
+
+pub fn cycle_equipped_weapon(
+    mut commands: Commands,
+    action_state: Res<ActionState>,
+    weapon_registry: Res<WeaponRegistry>,
+    mut player_ship_query: Query<(Entity, &mut EquippedWeapon), With<PlayerShip>>,
+) {
+    if let Ok((entity, mut equipped_weapon)) = player_ship_query.get_single_mut() {
+        let weapon_count = weapon_registry.count();
+        if action_state.just_pressed(InputAction::NextWeapon) {
+            equipped_weapon.next(weapon_count);
+        } else if action_state.just_pressed(InputAction::PreviousWeapon) {
+            equipped_weapon.previous(weapon_count);
+        } else {
+            return;
+        }
+        commands
+            .entity(entity)
+            .remove::<(ChargingShot, BurstFire)>();
+    }
+}
+
+pub fn expire_projectiles(
+    mut commands: Commands,
+    mut projectile_query: Query<(Entity, &mut Projectile, &LinearVelocity)>,
+    time: Res<Time>,
+) {
+    for (entity, mut projectile, linear_velocity) in projectile_query.iter_mut() {
+        projectile.lifetime.tick(time.delta());
+        // measured from velocity so wrapping around the field doesn't count as travel
+        projectile.travel(linear_velocity.length() * time.delta_seconds());
+        if projectile.expired() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub fn steer_homing_projectiles(
+    mut homing_query: Query<
+        (&mut Homing, &Faction, &mut Transform, &mut LinearVelocity),
+        With<Projectile>,
+    >,
+    target_query: Query<
+        (Entity, &GlobalTransform, Option<&Faction>),
+        (
+            Or<(With<Meteor>, With<Enemy>, With<BossPart>)>,
+            Without<Projectile>,
+        ),
+    >,
+    time: Res<Time>,
+) {
+    for (mut homing, faction, mut transform, mut linear_velocity) in homing_query.iter_mut() {
+        let position = transform.translation.truncate();
+        let forward = linear_velocity.normalize_or_zero();
+        // Boss parts are children, so targets are tracked by where they are in the world
+        let target_position = match homing
+            .target
+            .and_then(|target| target_query.get(target).ok())
+        {
+            Some((_, target_transform, _)) => Some(target_transform.translation().truncate()),
+            None => {
+                let nearest = target_query
+                    .iter()
+                    .filter(|(_, _, target_faction)| *target_faction != Some(faction))
+                    .map(|(entity, target_transform, _)| {
+                        (entity, target_transform.translation().truncate())
+                    })
+                    .filter(|(_, target_position)| {
+                        let to_target = *target_position - position;
+                        to_target.length() <= homing.range
+                            && util::angle_between(forward, to_target) <= homing.half_cone()
+                    })
+                    .min_by(|(_, a), (_, b)| {
+                        a.distance_squared(position)
+                            .total_cmp(&b.distance_squared(position))
+                    });
+                homing.target = nearest.map(|(entity, _)| entity);
+                nearest.map(|(_, target_position)| target_position)
+            }
+        };
+        let Some(target_position) = target_position else {
+            continue;
+        };
+        let to_target = target_position - position;
+        let heading = util::rotate_towards(
+            forward.y.atan2(forward.x),
+            to_target.y.atan2(to_target.x),
+            homing.turn_rate() * time.delta_seconds(),
+        );
+        linear_velocity.0 = Vec2::from_angle(heading) * linear_velocity.length();
+        // shot sprites point up the y axis
+        transform.rotation = Quat::from_rotation_z(heading - FRAC_PI_2);
+    }
+}
+
+pub fn spawn_weapon_at_position(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
     texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
     sprite_loader: &Res<XMLSpriteSheetLoader>,
     sprite_name: &str,
     weapon: Weapon,
-    ship_entity: Entity,
-    ship_transform: &Transform,
+    projectile: Projectile,
+    (ship_entity, faction): (Entity, Faction),
+    friendly_fire: &FriendlyFire,
+    shot_transform: Transform,
     force: LinearVelocity,
-) {
+) -> Entity {
     let texture_handle = asset_server.load(&sprite_loader.file);
     let sprite = sprite_loader.get_sprite(sprite_name).unwrap();
     let texture_atlas = TextureAtlas::from_grid(
@@ -73,10 +518,9 @@ fn spawn_weapon_at_position(
     );
     let texture_atlas_handle = texture_atlases.add(texture_atlas);
     let scale = weapon.scale;
-    let shot_transform = middle_shot_from_transform(ship_transform);
     let speed = weapon.speed;
     let mut collider = sprite_loader
-        .get_sprite_collider(sprite_name, 0, true)
+        .get_sprite_collider_or_bounds(sprite_name, 0, true)
         .unwrap();
     collider.set_scale(Vector::splat(scale), 1);
     commands
@@ -87,25 +531,25 @@ fn spawn_weapon_at_position(
                 ..default()
             },
             weapon,
+            projectile,
+            faction,
         ))
         .insert(shot_transform.with_scale(Vec3::splat(scale)))
         .insert(WeaponFireTimer { ..default() })
         .insert((
             RigidBody::Kinematic,
             ShapeCaster::new(collider, Vec2::ZERO, 0., Vec2::Y)
-                .with_ignore_origin_penetration(true),
-            // RayCaster::new(Vec2::ZERO, Vec2::Y)
-            //     .with_solidness(true)
-            //     .with_query_filter(SpatialQueryFilter::new().without_entities([ship_entity])),
+                .with_ignore_origin_penetration(true)
+                .with_query_filter(
+                    faction
+                        .shot_filter(friendly_fire)
+                        .without_entities([ship_entity]),
+                ),
             force,
             ColliderDensity(0.0),
             Mass(0.0),
-        ));
-}
-
-fn middle_shot_from_transform(transform: &Transform) -> Transform {
-    let shot_vec = Vec3::new(0.0, FIRE_DISTANCE_FROM_PLAYER, 0.0);
-    shot_from_transform(shot_vec, transform)
+        ))
+        .id()
 }
 
 fn shot_from_transform(shot_vec: Vec3, transform: &Transform) -> Transform {
@@ -122,40 +566,135 @@ fn shot_from_transform(shot_vec: Vec3, transform: &Transform) -> Transform {
     }
 }
 
-pub fn handle_shot_intersections_with_wall(
+/// The first thing a shot's cast has reached, if it's close enough to count as a hit
+pub fn shot_hit(hits: &ShapeHits) -> Option<&ShapeHitData> {
+    hits.iter().find(|hit| hit.time_of_impact <= 0.1)
+}
+
+/// Ends every shot that hits something, setting off explosive ones. Whatever was hit takes any
+/// direct damage in its own module
+pub fn handle_shot_hits(
     mut commands: Commands,
-    shot_query: Query<(Entity, &Transform), With<Weapon>>,
-    left_wall_query: Query<&Transform, With<LeftWall>>,
-    right_wall_query: Query<&Transform, With<RightWall>>,
-    top_wall_query: Query<&Transform, With<TopWall>>,
-    bottom_wall_query: Query<&Transform, With<BottomWall>>,
+    shot_query: Query<(
+        Entity,
+        &Weapon,
+        &Faction,
+        &Transform,
+        &ShapeHits,
+        Option<&Explosive>,
+    )>,
+    mut explosion_events: EventWriter<Explosion>,
 ) {
-    for (shot_entity, shot_transform) in shot_query.iter() {
-        let mut check_and_despawn = |check: bool| {
-            if check {
-                commands.entity(shot_entity).despawn();
-            }
-            check
-        };
-        if let Ok(wall_transform) = left_wall_query.get_single() {
-            if check_and_despawn(shot_transform.translation.x < wall_transform.translation.x) {
-                break;
+    for (shot_entity, shot, faction, shot_transform, hits, explosive) in shot_query.iter() {
+        if shot_hit(hits).is_some() {
+            if let Some(explosive) = explosive {
+                explosion_events.send(explosive.explosion(
+                    shot_transform.translation.truncate(),
+                    shot.damage,
+                    *faction,
+                ));
             }
+            commands.entity(shot_entity).despawn();
         }
-        if let Ok(wall_transform) = right_wall_query.get_single() {
-            if check_and_despawn(shot_transform.translation.x > wall_transform.translation.x) {
-                break;
-            }
+    }
+}
+
+pub fn handle_shot_intersections_with_wall(
+    mut commands: Commands,
+    mut shot_query: Query<(Entity, &mut Transform, Option<&Projectile>), With<Weapon>>,
+    left_wall_query: Query<&Transform, (With<LeftWall>, Without<Weapon>)>,
+    right_wall_query: Query<&Transform, (With<RightWall>, Without<Weapon>)>,
+    top_wall_query: Query<&Transform, (With<TopWall>, Without<Weapon>)>,
+    bottom_wall_query: Query<&Transform, (With<BottomWall>, Without<Weapon>)>,
+) {
+    let (Ok(left_wall), Ok(right_wall), Ok(top_wall), Ok(bottom_wall)) = (
+        left_wall_query.get_single(),
+        right_wall_query.get_single(),
+        top_wall_query.get_single(),
+        bottom_wall_query.get_single(),
+    ) else {
+        return;
+    };
+    let (left, right) = (left_wall.translation.x, right_wall.translation.x);
+    let (top, bottom) = (top_wall.translation.y, bottom_wall.translation.y);
+    for (shot_entity, mut shot_transform, projectile) in shot_query.iter_mut() {
+        let translation = shot_transform.translation;
+        let outside = translation.x < left
+            || translation.x > right
+            || translation.y > top
+            || translation.y < bottom;
+        if !outside {
+            continue;
         }
-        if let Ok(wall_transform) = top_wall_query.get_single() {
-            if check_and_despawn(shot_transform.translation.y > wall_transform.translation.y) {
-                break;
+        if projectile.is_some_and(|projectile| projectile.wraps) {
+            if translation.x < left {
+                shot_transform.translation.x = right;
+            } else if translation.x > right {
+                shot_transform.translation.x = left;
             }
-        }
-        if let Ok(wall_transform) = bottom_wall_query.get_single() {
-            if check_and_despawn(shot_transform.translation.y < wall_transform.translation.y) {
-                break;
+            if translation.y > top {
+                shot_transform.translation.y = bottom;
+            } else if translation.y < bottom {
+                shot_transform.translation.y = top;
             }
+        } else {
+            commands.entity(shot_entity).despawn();
         }
     }
 }
+
+pub fn spawn_weapon_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let text_style = weapon_hud_text_style(&asset_server);
+    commands.spawn((
+        TextBundle::from_sections([
+            TextSection::new("", text_style.clone()),
+            TextSection::new("", text_style.clone()),
+            TextSection::new("", text_style),
+        ])
+        .with_style(weapon_hud_style()),
+        WeaponHud,
+    ));
+}
+
+pub fn despawn_weapon_hud(mut commands: Commands, hud_query: Query<Entity, With<WeaponHud>>) {
+    for entity in hud_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn update_weapon_hud(
+    weapon_registry: Res<WeaponRegistry>,
+    mut player_ship_query: Query<
+        (&EquippedWeapon, &mut WeaponReserves, &ShipEnergy),
+        With<PlayerShip>,
+    >,
+    mut hud_query: Query<&mut Text, With<WeaponHud>>,
+) {
+    let Ok(mut text) = hud_query.get_single_mut() else {
+        return;
+    };
+    let Ok((equipped_weapon, mut weapon_reserves, ship_energy)) =
+        player_ship_query.get_single_mut()
+    else {
+        return;
+    };
+    let definition = weapon_registry.get(equipped_weapon.index);
+    let reserve = weapon_reserves.get_mut(equipped_weapon.index, definition);
+    let (status, warning) = match &definition.resource {
+        Some(WeaponResource::Ammo { capacity, .. }) => match reserve.reload {
+            Some(_) => ("Reloading".to_string(), true),
+            None => (format!("Ammo {}/{}", reserve.ammo, capacity), false),
+        },
+        Some(WeaponResource::Heat { .. }) if reserve.overheated => ("Overheated".to_string(), true),
+        Some(WeaponResource::Heat { .. }) => (format!("Heat {:.0}%", reserve.heat * 100.), false),
+        Some(WeaponResource::Energy { .. }) | None => (String::new(), false),
+    };
+    text.sections[0].value = format!("{}  ", definition.name);
+    text.sections[1].value = status;
+    text.sections[1].style.color = if warning {
+        WEAPON_HUD_WARNING_COLOR
+    } else {
+        WEAPON_HUD_TEXT_COLOR
+    };
+    text.sections[2].value = format!("\nEnergy {:.0}%", ship_energy.energy_pct() * 100.);
+}
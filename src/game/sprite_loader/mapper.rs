@@ -137,6 +137,21 @@ impl XMLSpriteSheetLoader {
         }
         None
     }
+
+    /// Like `get_sprite_collider`, but falls back to the sprite's bounding box for sprites
+    /// without collision shapes
+    pub fn get_sprite_collider_or_bounds(
+        &self,
+        sprite_name: &str,
+        frame: usize,
+        to_origin: bool,
+    ) -> Option<Collider> {
+        self.get_sprite_collider(sprite_name, frame, to_origin)
+            .or_else(|| {
+                self.get_sprite(sprite_name)
+                    .map(|sprite| Collider::cuboid(sprite.width, sprite.height))
+            })
+    }
 }
 
 #[derive(Deserialize, Serialize, Clone)]
@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+/// What a steering behaviour is aiming for, or running from
+#[derive(Debug, Clone, Copy)]
+pub enum SteeringTarget {
+    Position(Vec2),
+    /// Whichever ship the player is currently flying
+    Player,
+}
+
+#[derive(Debug, Clone)]
+pub enum Behaviour {
+    Seek(SteeringTarget),
+    Flee {
+        threat: SteeringTarget,
+        panic_distance: f32,
+    },
+    Arrive {
+        target: SteeringTarget,
+        slowing_radius: f32,
+    },
+    Pursue(SteeringTarget),
+    Evade {
+        threat: SteeringTarget,
+        panic_distance: f32,
+    },
+    Wander {
+        radius: f32,
+        distance: f32,
+        /// Most the wander angle can drift per second, in radians
+        jitter: f32,
+    },
+    /// Steer around planets and meteors in the way
+    AvoidObstacles {
+        look_ahead: f32,
+    },
+}
+
+/// Moves a body by blending weighted behaviours. Drives `ExternalForce` on bodies that have
+/// one and `LinearVelocity` directly on the rest
+#[derive(Component)]
+pub struct Steering {
+    pub behaviours: Vec<(Behaviour, f32)>,
+    pub max_speed: f32,
+    pub max_force: f32,
+    pub wander_angle: f32,
+}
+
+impl Steering {
+    pub fn new(max_speed: f32, max_force: f32) -> Self {
+        Steering {
+            behaviours: Vec::new(),
+            max_speed,
+            max_force,
+            wander_angle: 0.,
+        }
+    }
+
+    pub fn with(mut self, behaviour: Behaviour, weight: f32) -> Self {
+        self.behaviours.push((behaviour, weight));
+        self
+    }
+
+    /// Points every seek, arrive and pursue behaviour at `new_target`
+    pub fn retarget(&mut self, new_target: SteeringTarget) {
+        for (behaviour, _) in self.behaviours.iter_mut() {
+            match behaviour {
+                Behaviour::Seek(target)
+                | Behaviour::Arrive { target, .. }
+                | Behaviour::Pursue(target) => *target = new_target,
+                _ => {}
+            }
+        }
+    }
+}
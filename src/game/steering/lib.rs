@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+
+/// Head straight for `target` at full speed. Like the rest of these behaviours it returns the
+/// change in velocity wanted this frame
+pub fn seek(position: Vec2, velocity: Vec2, target: Vec2, max_speed: f32) -> Vec2 {
+    (target - position).normalize_or_zero() * max_speed - velocity
+}
+
+/// Run directly away from `threat` while it's within `panic_distance`
+pub fn flee(
+    position: Vec2,
+    velocity: Vec2,
+    threat: Vec2,
+    max_speed: f32,
+    panic_distance: f32,
+) -> Vec2 {
+    if position.distance(threat) > panic_distance {
+        return Vec2::ZERO;
+    }
+    (position - threat).normalize_or_zero() * max_speed - velocity
+}
+
+/// Like `seek`, but slowing down within `slowing_radius` to stop on the target
+pub fn arrive(
+    position: Vec2,
+    velocity: Vec2,
+    target: Vec2,
+    max_speed: f32,
+    slowing_radius: f32,
+) -> Vec2 {
+    let offset = target - position;
+    let distance = offset.length();
+    let speed = if distance < slowing_radius {
+        max_speed * distance / slowing_radius
+    } else {
+        max_speed
+    };
+    offset.normalize_or_zero() * speed - velocity
+}
+
+/// Seek where a moving target will be by the time we could get there
+pub fn pursue(
+    position: Vec2,
+    velocity: Vec2,
+    target: Vec2,
+    target_velocity: Vec2,
+    max_speed: f32,
+) -> Vec2 {
+    let prediction = predict(position, target, target_velocity, max_speed);
+    seek(position, velocity, prediction, max_speed)
+}
+
+/// Flee from where a moving threat will be
+pub fn evade(
+    position: Vec2,
+    velocity: Vec2,
+    threat: Vec2,
+    threat_velocity: Vec2,
+    max_speed: f32,
+    panic_distance: f32,
+) -> Vec2 {
+    if position.distance(threat) > panic_distance {
+        return Vec2::ZERO;
+    }
+    let prediction = predict(position, threat, threat_velocity, max_speed);
+    (position - prediction).normalize_or_zero() * max_speed - velocity
+}
+
+fn predict(position: Vec2, target: Vec2, target_velocity: Vec2, max_speed: f32) -> Vec2 {
+    if max_speed <= 0. {
+        return target;
+    }
+    target + target_velocity * position.distance(target) / max_speed
+}
+
+/// Meander by steering towards a point on a circle `distance` ahead, which drifts round by
+/// `jitter` radians each time
+pub fn wander(
+    velocity: Vec2,
+    wander_angle: &mut f32,
+    radius: f32,
+    distance: f32,
+    jitter: f32,
+) -> Vec2 {
+    *wander_angle += jitter;
+    let heading = velocity.try_normalize().unwrap_or(Vec2::Y);
+    heading * distance + Vec2::from_angle(*wander_angle) * radius
+}
+
+/// Steer sideways away from the nearest obstacle, given as a centre and radius, in the way of
+/// the next `look_ahead` units of travel
+pub fn avoid_obstacles(
+    position: Vec2,
+    velocity: Vec2,
+    obstacles: &[(Vec2, f32)],
+    look_ahead: f32,
+    max_speed: f32,
+) -> Vec2 {
+    let Some(heading) = velocity.try_normalize() else {
+        return Vec2::ZERO;
+    };
+    let nearest = obstacles
+        .iter()
+        .filter_map(|(centre, radius)| {
+            // closest point on the path ahead to the obstacle's centre
+            let along = (*centre - position).dot(heading).clamp(0., look_ahead);
+            let closest = position + heading * along;
+            (closest.distance(*centre) < *radius).then_some((along, closest, *centre))
+        })
+        .min_by(|a, b| a.0.total_cmp(&b.0));
+    match nearest {
+        Some((_, closest, centre)) => (closest - centre).normalize_or_zero() * max_speed,
+        None => Vec2::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrive_slows_down_near_target() {
+        let far = arrive(Vec2::ZERO, Vec2::ZERO, Vec2::new(100., 0.), 10., 20.);
+        let near = arrive(Vec2::ZERO, Vec2::ZERO, Vec2::new(10., 0.), 10., 20.);
+        assert_eq!(far, Vec2::new(10., 0.));
+        assert_eq!(near, Vec2::new(5., 0.));
+    }
+
+    #[test]
+    fn test_flee_ignores_distant_threats() {
+        let threat = Vec2::new(100., 0.);
+        assert_eq!(flee(Vec2::ZERO, Vec2::ZERO, threat, 10., 50.), Vec2::ZERO);
+        assert_eq!(
+            flee(Vec2::ZERO, Vec2::ZERO, threat, 10., 150.),
+            Vec2::new(-10., 0.)
+        );
+    }
+
+    #[test]
+    fn test_avoid_obstacles_steers_away_from_the_path() {
+        let obstacles = [(Vec2::new(50., 5.), 10.), (Vec2::new(500., 0.), 10.)];
+        let force = avoid_obstacles(Vec2::ZERO, Vec2::new(1., 0.), &obstacles, 100., 10.);
+        assert!(force.y < 0.);
+        let clear = avoid_obstacles(Vec2::ZERO, Vec2::new(0., 1.), &obstacles, 100., 10.);
+        assert_eq!(clear, Vec2::ZERO);
+    }
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+pub mod components;
+pub mod lib;
+mod systems;
+
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use systems::*;
+
+pub struct SteeringPlugin;
+
+impl Plugin for SteeringPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            apply_steering
+                .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use bevy_xpbd_2d::prelude::*;
+use rand::{thread_rng, Rng};
+
+use super::components::*;
+use super::lib as steering;
+use crate::game::meteors::components::Meteor;
+use crate::game::planets::components::Planet;
+use crate::game::player::components::PlayerShip;
+
+pub fn apply_steering(
+    mut steering_query: Query<(
+        &mut Steering,
+        &Transform,
+        &mut LinearVelocity,
+        Option<&mut ExternalForce>,
+        Option<&Mass>,
+    )>,
+    target_query: Query<(&Transform, Option<&LinearVelocity>), Without<Steering>>,
+    player_query: Query<Entity, With<PlayerShip>>,
+    meteor_query: Query<(&Transform, &ColliderAabb), With<Meteor>>,
+    planet_query: Query<&Planet>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_seconds();
+    let player = player_query.get_single().ok();
+    let resolve = |target: &SteeringTarget| -> Option<(Vec2, Vec2)> {
+        let entity = match target {
+            SteeringTarget::Position(position) => return Some((*position, Vec2::ZERO)),
+            SteeringTarget::Player => player?,
+        };
+        target_query
+            .get(entity)
+            .ok()
+            .map(|(transform, linear_velocity)| {
+                (
+                    transform.translation.truncate(),
+                    linear_velocity.map_or(Vec2::ZERO, |velocity| velocity.0),
+                )
+            })
+    };
+    let obstacles: Vec<(Vec2, f32)> = meteor_query
+        .iter()
+        .map(|(transform, aabb)| {
+            let half_extents = aabb.0.half_extents();
+            (
+                transform.translation.truncate(),
+                half_extents.x.max(half_extents.y),
+            )
+        })
+        .chain(
+            planet_query
+                .iter()
+                .map(|planet| (planet.coordinates, planet.radius)),
+        )
+        .collect();
+
+    for (mut steering, transform, mut linear_velocity, external_force, mass) in
+        steering_query.iter_mut()
+    {
+        let position = transform.translation.truncate();
+        let velocity = linear_velocity.0;
+        let max_speed = steering.max_speed;
+        let mut wander_angle = steering.wander_angle;
+        let mut force = Vec2::ZERO;
+        for (behaviour, weight) in steering.behaviours.iter() {
+            let steer = match behaviour {
+                Behaviour::Seek(target) => resolve(target)
+                    .map(|(target, _)| steering::seek(position, velocity, target, max_speed)),
+                Behaviour::Flee {
+                    threat,
+                    panic_distance,
+                } => resolve(threat).map(|(threat, _)| {
+                    steering::flee(position, velocity, threat, max_speed, *panic_distance)
+                }),
+                Behaviour::Arrive {
+                    target,
+                    slowing_radius,
+                } => resolve(target).map(|(target, _)| {
+                    steering::arrive(position, velocity, target, max_speed, *slowing_radius)
+                }),
+                Behaviour::Pursue(target) => resolve(target).map(|(target, target_velocity)| {
+                    steering::pursue(position, velocity, target, target_velocity, max_speed)
+                }),
+                Behaviour::Evade {
+                    threat,
+                    panic_distance,
+                } => resolve(threat).map(|(threat, threat_velocity)| {
+                    steering::evade(
+                        position,
+                        velocity,
+                        threat,
+                        threat_velocity,
+                        max_speed,
+                        *panic_distance,
+                    )
+                }),
+                Behaviour::Wander {
+                    radius,
+                    distance,
+                    jitter,
+                } => {
+                    let jitter = thread_rng().gen_range(-*jitter..=*jitter) * delta;
+                    Some(steering::wander(
+                        velocity,
+                        &mut wander_angle,
+                        *radius,
+                        *distance,
+                        jitter,
+                    ))
+                }
+                Behaviour::AvoidObstacles { look_ahead } => Some(steering::avoid_obstacles(
+                    position,
+                    velocity,
+                    &obstacles,
+                    *look_ahead,
+                    max_speed,
+                )),
+            };
+            force += steer.unwrap_or(Vec2::ZERO) * *weight;
+        }
+        steering.wander_angle = wander_angle;
+        let force = force.clamp_length_max(steering.max_force);
+        match (external_force, mass) {
+            (Some(mut external_force), Some(mass)) => {
+                external_force.set_force(force * mass.0);
+            }
+            _ => {
+                linear_velocity.0 = (velocity + force * delta).clamp_length_max(max_speed);
+            }
+        }
+    }
+}
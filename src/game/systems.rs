@@ -1,3 +1,4 @@
+use crate::game::controls::resources::{ActionState, InputAction};
 use crate::game::states::SimulationState;
 use bevy::prelude::*;
 
@@ -10,11 +11,11 @@ pub fn resume_simulation(mut next_sim_state: ResMut<NextState<SimulationState>>)
 }
 
 pub fn toggle_simulation(
-    keyboard_input: Res<Input<KeyCode>>,
+    action_state: Res<ActionState>,
     simulation_state: Res<State<SimulationState>>,
     mut next_sim_state: ResMut<NextState<SimulationState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Escape) {
+    if action_state.just_pressed(InputAction::Pause) {
         match *simulation_state.get() {
             SimulationState::Running => {
                 next_sim_state.set(SimulationState::Paused);
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::damage::{Damage, Damageable};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurretKind {
+    Big,
+    Small,
+}
+
+impl TurretKind {
+    pub fn base_sprite_name(&self) -> &'static str {
+        match self {
+            TurretKind::Big => "turretBase_big.png",
+            TurretKind::Small => "turretBase_small.png",
+        }
+    }
+
+    pub fn gun_sprite_name(&self) -> &'static str {
+        match self {
+            TurretKind::Big => "gun09.png",
+            TurretKind::Small => "gun04.png",
+        }
+    }
+
+    pub fn range(&self) -> f32 {
+        match self {
+            TurretKind::Big => 450.,
+            TurretKind::Small => 300.,
+        }
+    }
+
+    pub fn health(&self) -> f32 {
+        match self {
+            TurretKind::Big => 1200.,
+            TurretKind::Small => 600.,
+        }
+    }
+
+    /// Damage dealt to a meteor that hits the turret, enough to break any meteor up on the spot
+    pub fn impact_damage(&self) -> f32 {
+        match self {
+            TurretKind::Big => 400.,
+            TurretKind::Small => 200.,
+        }
+    }
+
+    pub fn fire_delay(&self) -> f32 {
+        match self {
+            TurretKind::Big => 1.2,
+            TurretKind::Small => 0.4,
+        }
+    }
+
+    pub fn shot_damage(&self) -> f32 {
+        match self {
+            TurretKind::Big => 60.,
+            TurretKind::Small => 15.,
+        }
+    }
+
+    /// Radians per second the gun can swing
+    pub fn turn_rate(&self) -> f32 {
+        match self {
+            TurretKind::Big => 2.,
+            TurretKind::Small => 4.,
+        }
+    }
+}
+
+/// A gun emplacement anchored to a planet's surface
+#[derive(Component)]
+pub struct Turret {
+    pub kind: TurretKind,
+    /// Direction from the planet's centre to the turret, the gun never aims below this horizon
+    pub outward: Vec2,
+    /// World angle the gun points along, in radians
+    pub aim: f32,
+    health: f32,
+    pub fire_timer: Timer,
+}
+
+impl Turret {
+    pub fn new(kind: TurretKind, outward: Vec2) -> Self {
+        Turret {
+            kind,
+            outward,
+            aim: outward.y.atan2(outward.x),
+            health: kind.health(),
+            fire_timer: Timer::from_seconds(kind.fire_delay(), TimerMode::Once),
+        }
+    }
+
+    pub fn health_pct(&self) -> f32 {
+        self.health / self.kind.health()
+    }
+
+    pub fn can_see(&self, position: Vec2, target: Vec2) -> bool {
+        let to_target = target - position;
+        to_target.length() <= self.kind.range() && to_target.dot(self.outward) > 0.
+    }
+}
+
+impl Damageable for Turret {
+    fn damage(&mut self, entity: &impl Damage) {
+        self.health -= entity.hit_points();
+    }
+
+    fn health(&self) -> f32 {
+        self.health
+    }
+}
+
+impl Damage for Turret {
+    fn hit_points(&self) -> f32 {
+        self.kind.impact_damage()
+    }
+}
+
+/// The barrel sprite, a child of the `Turret` base that swings to follow `Turret::aim`
+#[derive(Component)]
+pub struct TurretGun;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turret_sees_targets_in_range_above_its_horizon() {
+        let turret = Turret::new(TurretKind::Small, Vec2::Y);
+        let position = Vec2::new(100., 100.);
+        let range = TurretKind::Small.range();
+        assert!(turret.can_see(position, position + Vec2::new(0., range - 1.)));
+        assert!(turret.can_see(position, position + Vec2::new(50., 10.)));
+        assert!(!turret.can_see(position, position + Vec2::new(0., range + 1.)));
+        assert!(!turret.can_see(position, position + Vec2::new(0., -50.)));
+        assert!(!turret.can_see(position, position + Vec2::new(50., 0.)));
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+
+pub mod components;
+mod systems;
+
+use crate::game::damage::systems::apply_explosions;
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use components::Turret;
+use systems::*;
+
+pub const TURRETS_PER_PLANET: usize = 4;
+pub const TURRET_SCALE: f32 = 0.8;
+// the gun only fires once it points this close to its target, in radians
+pub const TURRET_AIM_TOLERANCE: f32 = 0.1;
+pub const TURRET_SHOT_SPRITE: &str = "laserBlue03.png";
+pub const TURRET_SHOT_SPEED: f32 = 700.;
+pub const TURRET_SHOT_LIFETIME: f32 = 1.;
+
+pub struct TurretsPlugin;
+
+impl Plugin for TurretsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                spawn_turrets_on_new_planets,
+                aim_and_fire_turrets,
+                (
+                    handle_shot_hits_on_turrets,
+                    handle_turret_collision_with_meteor,
+                    apply_explosions::<Turret>,
+                    handle_turret_destruction,
+                )
+                    .chain(),
+                render_turret_health,
+            )
+                .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+        )
+        .add_systems(OnExit(AppState::Game), despawn_turrets);
+    }
+}
@@ -0,0 +1,224 @@
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+use bevy_xpbd_2d::prelude::*;
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use super::components::*;
+use super::*;
+use crate::game::damage::components::Faction;
+use crate::game::damage::resources::FriendlyFire;
+use crate::game::damage::Damageable;
+use crate::game::enemies::components::Enemy;
+use crate::game::meteors::components::Meteor;
+use crate::game::planets::components::Planet;
+use crate::game::shots::components::{Explosive, Projectile, Weapon};
+use crate::game::shots::resources::WeaponDefinition;
+use crate::game::shots::systems as shots_systems;
+use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
+use crate::game::util;
+use crate::game::world::systems as world_systems;
+use crate::states::GameMode;
+
+pub fn despawn_turrets(mut commands: Commands, turret_query: Query<Entity, With<Turret>>) {
+    for entity in turret_query.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Rings every planet with turrets when playing planet defence
+pub fn spawn_turrets_on_new_planets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    game_mode: Res<GameMode>,
+    planet_query: Query<&Planet, Added<Planet>>,
+) {
+    if *game_mode != GameMode::PlanetDefence {
+        return;
+    }
+    for planet in planet_query.iter() {
+        for i in 0..TURRETS_PER_PLANET {
+            let kind = if i % 2 == 0 {
+                TurretKind::Big
+            } else {
+                TurretKind::Small
+            };
+            let angle = FRAC_PI_2 + TAU * i as f32 / TURRETS_PER_PLANET as f32;
+            let outward = Vec2::from_angle(angle);
+            let base = sprite_loader.get_sprite(kind.base_sprite_name()).unwrap();
+            let position =
+                planet.coordinates + outward * (planet.radius + base.half_height() * TURRET_SCALE);
+            let mut gun = world_systems::sprite_sheet_bundle(
+                &asset_server,
+                &mut texture_atlases,
+                &sprite_loader,
+                kind.gun_sprite_name(),
+                1,
+                1,
+                0,
+            );
+            // swing the barrel around its breech rather than its middle
+            gun.sprite.anchor = Anchor::BottomCenter;
+            gun.transform = Transform::from_xyz(0., 0., 0.1);
+            commands
+                .spawn((
+                    world_systems::sprite_sheet_bundle(
+                        &asset_server,
+                        &mut texture_atlases,
+                        &sprite_loader,
+                        kind.base_sprite_name(),
+                        1,
+                        1,
+                        0,
+                    ),
+                    Turret::new(kind, outward),
+                    Faction::Player.bundle(),
+                ))
+                .insert(
+                    Transform::from_translation(position.extend(0.))
+                        .with_rotation(Quat::from_rotation_z(angle - FRAC_PI_2))
+                        .with_scale(Vec3::splat(TURRET_SCALE)),
+                )
+                .insert((RigidBody::Static, Collider::ball(base.half_width())))
+                .with_children(|parent| {
+                    parent.spawn((gun, TurretGun));
+                });
+        }
+    }
+}
+
+/// Swings each gun toward the nearest meteor or saucer above its horizon and fires once on target
+pub fn aim_and_fire_turrets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    sprite_loader: Res<XMLSpriteSheetLoader>,
+    friendly_fire: Res<FriendlyFire>,
+    mut turret_query: Query<(Entity, &Faction, &mut Turret, &Transform, &Children)>,
+    mut gun_query: Query<&mut Transform, (With<TurretGun>, Without<Turret>)>,
+    target_query: Query<
+        &Transform,
+        (
+            Or<(With<Meteor>, With<Enemy>)>,
+            Without<Turret>,
+            Without<TurretGun>,
+        ),
+    >,
+    time: Res<Time>,
+) {
+    for (entity, faction, mut turret, transform, children) in turret_query.iter_mut() {
+        turret.fire_timer.tick(time.delta());
+        let position = transform.translation.truncate();
+        let Some(target) = target_query
+            .iter()
+            .map(|target_transform| target_transform.translation.truncate())
+            .filter(|target| turret.can_see(position, *target))
+            .min_by(|a, b| {
+                a.distance_squared(position)
+                    .total_cmp(&b.distance_squared(position))
+            })
+        else {
+            continue;
+        };
+        let to_target = target - position;
+        let wanted = to_target.y.atan2(to_target.x);
+        turret.aim = util::rotate_towards(
+            turret.aim,
+            wanted,
+            turret.kind.turn_rate() * time.delta_seconds(),
+        );
+        let base_angle = turret.outward.y.atan2(turret.outward.x);
+        for child in children.iter() {
+            if let Ok(mut gun_transform) = gun_query.get_mut(*child) {
+                gun_transform.rotation = Quat::from_rotation_z(turret.aim - base_angle);
+            }
+        }
+        if util::wrap_angle(wanted - turret.aim).abs() > TURRET_AIM_TOLERANCE
+            || !turret.fire_timer.finished()
+        {
+            continue;
+        }
+        turret.fire_timer.reset();
+
+        let direction = Vec2::from_angle(turret.aim);
+        let barrel_length = sprite_loader
+            .get_sprite(turret.kind.gun_sprite_name())
+            .map_or(0., |sprite| sprite.height);
+        let muzzle = position + direction * barrel_length * TURRET_SCALE;
+        let definition = WeaponDefinition {
+            sprite_name: TURRET_SHOT_SPRITE.to_string(),
+            damage: turret.kind.shot_damage(),
+            speed: TURRET_SHOT_SPEED,
+            lifetime: TURRET_SHOT_LIFETIME,
+            ..default()
+        };
+        // shot sprites point up the y axis
+        let shot_transform = Transform::from_translation(muzzle.extend(0.))
+            .with_rotation(Quat::from_rotation_z(turret.aim - FRAC_PI_2));
+        shots_systems::spawn_weapon_at_position(
+            &mut commands,
+            &asset_server,
+            &mut texture_atlases,
+            &sprite_loader,
+            TURRET_SHOT_SPRITE,
+            Weapon::from_definition(&definition),
+            Projectile::from_definition(&definition),
+            (entity, *faction),
+            &friendly_fire,
+            shot_transform,
+            LinearVelocity(direction * definition.speed),
+        );
+    }
+}
+
+pub fn handle_shot_hits_on_turrets(
+    shot_query: Query<(&Weapon, &ShapeHits), Without<Explosive>>,
+    mut turret_query: Query<&mut Turret>,
+) {
+    for (shot, hits) in shot_query.iter() {
+        if let Some(hit) = shots_systems::shot_hit(hits) {
+            if let Ok(mut turret) = turret_query.get_mut(hit.entity) {
+                turret.damage(shot);
+            }
+        }
+    }
+}
+
+/// Meteors break up on the turrets they hit, taking some of the turret with them
+pub fn handle_turret_collision_with_meteor(
+    mut turret_query: Query<(&mut Turret, &CollidingEntities)>,
+    mut meteor_query: Query<&mut Meteor>,
+) {
+    for (mut turret, colliding_entities) in turret_query.iter_mut() {
+        for entity in colliding_entities.iter() {
+            if let Ok(mut meteor) = meteor_query.get_mut(*entity) {
+                if meteor.is_dead() {
+                    continue;
+                }
+                turret.damage(&*meteor);
+                meteor.damage(&*turret);
+            }
+        }
+    }
+}
+
+pub fn handle_turret_destruction(mut commands: Commands, turret_query: Query<(Entity, &Turret)>) {
+    for (entity, turret) in turret_query.iter() {
+        if turret.is_dead() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn render_turret_health(mut gizmos: Gizmos, turret_query: Query<(&Turret, &Transform)>) {
+    for (turret, transform) in turret_query.iter() {
+        if !turret.is_dead() {
+            let offset = 15f32;
+            let start =
+                transform.translation.truncate() + turret.outward * offset * 2. - Vec2::X * offset;
+            let end = start + Vec2::X * 2. * offset * turret.health_pct();
+            gizmos.line_2d(start, end, Color::LIME_GREEN);
+        }
+    }
+}
@@ -1,13 +1,193 @@
 use bevy::prelude::*;
+use std::f32::consts::{PI, TAU};
 
+/// Unsigned angle in radians between `a` and `b`, in `[0, PI]`. Zero length vectors have no
+/// direction, so the angle to or from them is treated as 0
 pub fn angle_between(a: Vec2, b: Vec2) -> f32 {
-    (a.dot(b) / (magnitude(a) * magnitude(b))).acos()
+    let lengths = magnitude(a) * magnitude(b);
+    if lengths <= f32::EPSILON {
+        return 0.;
+    }
+    (a.dot(b) / lengths).clamp(-1., 1.).acos()
 }
 
-fn magnitude(a: Vec2) -> f32 {
-    (a.x * a.x).sqrt() + (a.y * a.y).sqrt()
+/// Euclidean length of `a`
+pub fn magnitude(a: Vec2) -> f32 {
+    (a.x * a.x + a.y * a.y).sqrt()
 }
 
+/// Angle in radians to rotate `from` onto `to`, in `[-PI, PI]`. Positive is counter-clockwise
+pub fn signed_angle(from: Vec2, to: Vec2) -> f32 {
+    if magnitude(from) * magnitude(to) <= f32::EPSILON {
+        return 0.;
+    }
+    from.perp_dot(to).atan2(from.dot(to))
+}
+
+/// True if the shortest rotation from `from` onto `to` is clockwise
 pub fn is_clockwise(from: Vec2, to: Vec2) -> bool {
-    angle_between(from, to) <= 180f32
+    signed_angle(from, to) < 0.
+}
+
+/// Wraps `angle` in radians into `(-PI, PI]`
+pub fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = (angle + PI).rem_euclid(TAU) - PI;
+    if wrapped <= -PI {
+        wrapped + TAU
+    } else {
+        wrapped
+    }
+}
+
+/// Turns the angle `from` toward `to` by at most `max_delta` radians, taking the shortest way round
+pub fn rotate_towards(from: f32, to: f32, max_delta: f32) -> f32 {
+    let delta = wrap_angle(to - from);
+    if delta.abs() <= max_delta {
+        wrap_angle(to)
+    } else {
+        wrap_angle(from + max_delta.copysign(delta))
+    }
+}
+
+/// Interpolates between the angles `a` and `b` along the shortest arc, `t` in `[0, 1]`
+pub fn lerp_angle(a: f32, b: f32, t: f32) -> f32 {
+    wrap_angle(a + wrap_angle(b - a) * t)
+}
+
+/// `v` rotated 90 degrees counter-clockwise
+pub fn perpendicular(v: Vec2) -> Vec2 {
+    Vec2::new(-v.y, v.x)
+}
+
+/// Reflects `v` off a surface with the given `normal`, which does not need to be normalized
+pub fn reflect(v: Vec2, normal: Vec2) -> Vec2 {
+    let normal = normal.normalize_or_zero();
+    v - 2. * v.dot(normal) * normal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EPSILON: f32 = 1e-3;
+
+    fn vec2() -> impl Strategy<Value = Vec2> {
+        (-1e3f32..1e3, -1e3f32..1e3).prop_map(|(x, y)| Vec2::new(x, y))
+    }
+
+    fn non_zero_vec2() -> impl Strategy<Value = Vec2> {
+        vec2().prop_filter("non zero", |v| v.length() > 1e-2)
+    }
+
+    fn angle() -> impl Strategy<Value = f32> {
+        -100f32..100.
+    }
+
+    fn angles_eq(a: f32, b: f32) -> bool {
+        wrap_angle(a - b).abs() < EPSILON
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(magnitude(Vec2::new(3., 4.)), 5.);
+        assert_eq!(magnitude(Vec2::ZERO), 0.);
+    }
+
+    #[test]
+    fn test_angle_between_zero_vector() {
+        assert_eq!(angle_between(Vec2::ZERO, Vec2::X), 0.);
+        assert_eq!(angle_between(Vec2::X, Vec2::ZERO), 0.);
+        assert_eq!(signed_angle(Vec2::ZERO, Vec2::ZERO), 0.);
+    }
+
+    #[test]
+    fn test_is_clockwise() {
+        assert!(is_clockwise(Vec2::Y, Vec2::X));
+        assert!(!is_clockwise(Vec2::X, Vec2::Y));
+        assert!(is_clockwise(Vec2::Y, Vec2::new(1., -1.)));
+    }
+
+    #[test]
+    fn test_reflect() {
+        assert_eq!(reflect(Vec2::new(1., -1.), Vec2::Y), Vec2::new(1., 1.));
+        assert_eq!(
+            reflect(Vec2::new(1., -1.), Vec2::Y * 10.),
+            Vec2::new(1., 1.)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn magnitude_matches_length(v in vec2()) {
+            prop_assert!((magnitude(v) - v.length()).abs() < EPSILON);
+        }
+
+        #[test]
+        fn angle_between_is_bounded(a in vec2(), b in vec2()) {
+            let angle = angle_between(a, b);
+            prop_assert!(!angle.is_nan());
+            prop_assert!((0. ..=PI).contains(&angle));
+        }
+
+        #[test]
+        fn signed_angle_matches_unsigned(a in non_zero_vec2(), b in non_zero_vec2()) {
+            prop_assert!((signed_angle(a, b).abs() - angle_between(a, b)).abs() < EPSILON);
+            prop_assert!(angles_eq(signed_angle(a, b), -signed_angle(b, a)));
+        }
+
+        #[test]
+        fn signed_angle_rotates_onto_target(a in non_zero_vec2(), b in non_zero_vec2()) {
+            let rotated = Vec2::from_angle(signed_angle(a, b)).rotate(a.normalize());
+            prop_assert!(rotated.distance(b.normalize()) < EPSILON);
+        }
+
+        #[test]
+        fn wrap_angle_is_bounded_and_equivalent(a in angle()) {
+            let wrapped = wrap_angle(a);
+            prop_assert!(wrapped > -PI && wrapped <= PI);
+            prop_assert!((wrapped.sin() - a.sin()).abs() < EPSILON);
+            prop_assert!((wrapped.cos() - a.cos()).abs() < EPSILON);
+        }
+
+        #[test]
+        fn rotate_towards_never_overshoots(from in angle(), to in angle(), max_delta in 0f32..PI) {
+            let rotated = rotate_towards(from, to, max_delta);
+            let remaining = wrap_angle(to - rotated).abs();
+            prop_assert!(wrap_angle(rotated - from).abs() <= max_delta + EPSILON);
+            prop_assert!(remaining <= wrap_angle(to - from).abs() + EPSILON);
+        }
+
+        #[test]
+        fn rotate_towards_reaches_target(from in angle(), to in angle()) {
+            prop_assert!(angles_eq(rotate_towards(from, to, PI), to));
+        }
+
+        #[test]
+        fn lerp_angle_endpoints(a in angle(), b in angle()) {
+            prop_assert!(angles_eq(lerp_angle(a, b, 0.), a));
+            prop_assert!(angles_eq(lerp_angle(a, b, 1.), b));
+        }
+
+        #[test]
+        fn lerp_angle_takes_shortest_arc(a in angle(), b in angle(), t in 0f32..1.) {
+            let step = wrap_angle(lerp_angle(a, b, t) - a).abs();
+            prop_assert!(step <= wrap_angle(b - a).abs() + EPSILON);
+        }
+
+        #[test]
+        fn perpendicular_is_orthogonal(v in vec2()) {
+            let p = perpendicular(v);
+            prop_assert!(v.dot(p).abs() < EPSILON);
+            prop_assert!((p.length() - v.length()).abs() < EPSILON);
+        }
+
+        #[test]
+        fn reflect_preserves_length(v in vec2(), normal in non_zero_vec2()) {
+            let reflected = reflect(v, normal);
+            let normal = normal.normalize();
+            prop_assert!((reflected.length() - v.length()).abs() < 1e-2);
+            prop_assert!((reflected.dot(normal) + v.dot(normal)).abs() < 1e-2);
+        }
+    }
 }
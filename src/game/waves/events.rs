@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Sent every time a new wave begins, not for the first one
+#[derive(Event)]
+pub struct WaveStarted {
+    pub number: u32,
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+pub mod events;
+pub mod resources;
+mod systems;
+
+use crate::game::states::SimulationState;
+use crate::states::AppState;
+use events::WaveStarted;
+use resources::Wave;
+use systems::*;
+
+pub const WAVE_DURATION: f32 = 45.;
+
+pub struct WavesPlugin;
+
+impl Plugin for WavesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wave>()
+            .add_event::<WaveStarted>()
+            .add_systems(OnEnter(AppState::Game), reset_waves)
+            .add_systems(
+                Update,
+                advance_waves
+                    .run_if(in_state(AppState::Game).and_then(in_state(SimulationState::Running))),
+            );
+    }
+}
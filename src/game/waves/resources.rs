@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use super::WAVE_DURATION;
+
+/// The game is split into waves of fixed length, counted from 1
+#[derive(Resource)]
+pub struct Wave {
+    pub number: u32,
+    pub timer: Timer,
+}
+
+impl Default for Wave {
+    fn default() -> Wave {
+        Wave {
+            number: 1,
+            timer: Timer::from_seconds(WAVE_DURATION, TimerMode::Repeating),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+use super::events::WaveStarted;
+use super::resources::Wave;
+
+pub fn reset_waves(mut wave: ResMut<Wave>) {
+    *wave = Wave::default();
+}
+
+pub fn advance_waves(
+    mut wave: ResMut<Wave>,
+    mut wave_started_events: EventWriter<WaveStarted>,
+    time: Res<Time>,
+) {
+    if wave.timer.tick(time.delta()).just_finished() {
+        wave.number += 1;
+        info!("Wave {}", wave.number);
+        wave_started_events.send(WaveStarted {
+            number: wave.number,
+        });
+    }
+}
@@ -121,24 +121,17 @@ pub fn spawn_sprite_frame_at_position_with_collider<T: Component, B: Bundle>(
     extras: Option<B>,
     collider: Collider,
 ) {
-    let texture_handle = asset_server.load(&sprite_loader.file);
-    let sprite = sprite_loader.get_sprite(sprite_name).unwrap();
-    let texture_atlas = TextureAtlas::from_grid(
-        texture_handle,
-        Vec2::new(sprite.width, sprite.height),
-        frame_cols,
-        frame_rows,
-        None,
-        Some(Vec2::new(sprite.x, sprite.y)),
-    );
-    let texture_atlas_handle = texture_atlases.add(texture_atlas);
     let spawned = commands
         .spawn((
-            SpriteSheetBundle {
-                texture_atlas: texture_atlas_handle,
-                sprite: TextureAtlasSprite::new(start_frame),
-                ..default()
-            },
+            sprite_sheet_bundle(
+                asset_server,
+                texture_atlases,
+                sprite_loader,
+                sprite_name,
+                frame_cols,
+                frame_rows,
+                start_frame,
+            ),
             component,
         ))
         .insert(transform.with_scale(Vec3::splat(scale)))
@@ -155,6 +148,33 @@ pub fn spawn_sprite_frame_at_position_with_collider<T: Component, B: Bundle>(
     }
 }
 
+/// A sprite cut out of the main sprite sheet, without any physics attached
+pub fn sprite_sheet_bundle(
+    asset_server: &Res<AssetServer>,
+    texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
+    sprite_loader: &Res<XMLSpriteSheetLoader>,
+    sprite_name: &str,
+    frame_cols: usize,
+    frame_rows: usize,
+    start_frame: usize,
+) -> SpriteSheetBundle {
+    let texture_handle = asset_server.load(&sprite_loader.file);
+    let sprite = sprite_loader.get_sprite(sprite_name).unwrap();
+    let texture_atlas = TextureAtlas::from_grid(
+        texture_handle,
+        Vec2::new(sprite.width, sprite.height),
+        frame_cols,
+        frame_rows,
+        None,
+        Some(Vec2::new(sprite.x, sprite.y)),
+    );
+    SpriteSheetBundle {
+        texture_atlas: texture_atlases.add(texture_atlas),
+        sprite: TextureAtlasSprite::new(start_frame),
+        ..default()
+    }
+}
+
 pub fn handle_mapping_cursor_to_world(
     mut coords: ResMut<WorldCoordinates>,
     // query to get the window (so we can read the current cursor position)
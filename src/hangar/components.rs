@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use crate::game::player::resources::PartSlot;
+
+#[derive(Component)]
+pub struct Hangar {}
+
+/// A line of the loadout that can be stepped through
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoadoutRow {
+    Frame,
+    Colour,
+    Part(PartSlot),
+}
+
+impl LoadoutRow {
+    pub const ALL: [LoadoutRow; 6] = [
+        LoadoutRow::Frame,
+        LoadoutRow::Colour,
+        LoadoutRow::Part(PartSlot::Cockpit),
+        LoadoutRow::Part(PartSlot::Wings),
+        LoadoutRow::Part(PartSlot::Engine),
+        LoadoutRow::Part(PartSlot::Guns),
+    ];
+}
+
+/// Steps the choice for `row` back or forward
+#[derive(Component)]
+pub struct LoadoutButton {
+    pub row: LoadoutRow,
+    pub step: i32,
+}
+
+#[derive(Component)]
+pub struct LoadoutText {
+    pub row: LoadoutRow,
+}
+
+#[derive(Component)]
+pub struct StatsText {}
+
+#[derive(Component)]
+pub struct LaunchButton {}
+
+#[derive(Component)]
+pub struct BackButton {}
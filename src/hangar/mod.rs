@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+mod components;
+mod styles;
+mod systems;
+
+use crate::states::AppState;
+use systems::interactions::*;
+use systems::layout::*;
+
+pub struct HangarPlugin;
+
+impl Plugin for HangarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Hangar), spawn_hangar)
+            .add_systems(
+                Update,
+                (
+                    interact_with_loadout_buttons,
+                    interact_with_launch_button,
+                    interact_with_back_button,
+                    update_loadout_text,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Hangar)),
+            )
+            .add_systems(OnExit(AppState::Hangar), despawn_hangar);
+    }
+}
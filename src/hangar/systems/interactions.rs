@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+
+use crate::game::player::resources::{ShipLoadout, ShipPartRegistry};
+use crate::hangar::components::*;
+use crate::hangar::styles::*;
+use crate::hangar::systems::layout::{row_label, stats_label};
+use crate::states::AppState;
+
+pub fn interact_with_loadout_buttons(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &LoadoutButton),
+        Changed<Interaction>,
+    >,
+    part_registry: Res<ShipPartRegistry>,
+    mut loadout: ResMut<ShipLoadout>,
+) {
+    for (interaction, mut background_color, loadout_button) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                let step = loadout_button.step;
+                match loadout_button.row {
+                    LoadoutRow::Frame => loadout.cycle_frame(step, &part_registry),
+                    LoadoutRow::Colour => loadout.cycle_colour(step),
+                    LoadoutRow::Part(slot) => loadout.cycle(slot, step, &part_registry),
+                }
+            }
+            Interaction::Hovered => {
+                *background_color = HOVERED_BUTTON_COLOR.into();
+            }
+            Interaction::None => {
+                *background_color = NORMAL_BUTTON_COLOR.into();
+            }
+        }
+    }
+}
+
+pub fn interact_with_launch_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<LaunchButton>),
+    >,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::Game);
+            }
+            Interaction::Hovered => {
+                *background_color = HOVERED_BUTTON_COLOR.into();
+            }
+            Interaction::None => {
+                *background_color = NORMAL_BUTTON_COLOR.into();
+            }
+        }
+    }
+}
+
+pub fn interact_with_back_button(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<BackButton>),
+    >,
+    mut app_state_next_state: ResMut<NextState<AppState>>,
+) {
+    if let Ok((interaction, mut background_color)) = button_query.get_single_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                app_state_next_state.set(AppState::MainMenu);
+            }
+            Interaction::Hovered => {
+                *background_color = HOVERED_BUTTON_COLOR.into();
+            }
+            Interaction::None => {
+                *background_color = NORMAL_BUTTON_COLOR.into();
+            }
+        }
+    }
+}
+
+pub fn update_loadout_text(
+    part_registry: Res<ShipPartRegistry>,
+    loadout: Res<ShipLoadout>,
+    mut loadout_text_query: Query<(&mut Text, &LoadoutText), Without<StatsText>>,
+    mut stats_text_query: Query<&mut Text, With<StatsText>>,
+) {
+    if !loadout.is_changed() {
+        return;
+    }
+    for (mut text, loadout_text) in loadout_text_query.iter_mut() {
+        text.sections[0].value = row_label(loadout_text.row, &loadout, &part_registry);
+    }
+    if let Ok(mut text) = stats_text_query.get_single_mut() {
+        text.sections[0].value = stats_label(&loadout, &part_registry);
+    }
+}
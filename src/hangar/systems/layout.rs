@@ -0,0 +1,190 @@
+use bevy::prelude::*;
+
+use crate::game::player::resources::{ShipLoadout, ShipPartRegistry};
+use crate::hangar::components::*;
+use crate::hangar::styles::*;
+
+pub fn spawn_hangar(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    part_registry: Res<ShipPartRegistry>,
+    loadout: Res<ShipLoadout>,
+) {
+    build_hangar(&mut commands, &asset_server, &part_registry, &loadout);
+}
+
+pub fn despawn_hangar(mut commands: Commands, hangar_query: Query<Entity, With<Hangar>>) {
+    if let Ok(hangar_entity) = hangar_query.get_single() {
+        commands.entity(hangar_entity).despawn_recursive();
+    }
+}
+
+pub fn row_label(row: LoadoutRow, loadout: &ShipLoadout, registry: &ShipPartRegistry) -> String {
+    let frame = loadout.frame(registry);
+    match (row, frame) {
+        (LoadoutRow::Frame, Some(frame)) => format!("Frame: {}", frame.name),
+        (LoadoutRow::Frame, None) => "Frame: Custom".to_string(),
+        (LoadoutRow::Colour, Some(_)) => format!("Colour: {}", capitalise(loadout.colour())),
+        (LoadoutRow::Colour, None) => "Colour: Parts".to_string(),
+        (LoadoutRow::Part(slot), Some(_)) => format!("{}: Stock", slot.label()),
+        (LoadoutRow::Part(slot), None) => {
+            format!("{}: {}", slot.label(), loadout.part(slot, registry).name)
+        }
+    }
+}
+
+fn capitalise(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or(String::new(), |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+pub fn stats_label(loadout: &ShipLoadout, registry: &ShipPartRegistry) -> String {
+    // Sprite sizes only feed the radius, which isn't shown here
+    let stats = loadout.stats(registry, |_| Vec2::ZERO);
+    format!(
+        "Mass {:.2}  Thrust {:.0}  Health {:.0}  Guns {}",
+        stats.mass,
+        stats.thrust,
+        stats.health,
+        stats.hardpoints.len()
+    )
+}
+
+fn spawn_button_text(parent: &mut ChildBuilder, label: &str, style: TextStyle) {
+    parent.spawn(TextBundle {
+        text: Text {
+            sections: vec![TextSection::new(label, style)],
+            alignment: TextAlignment::Center,
+            ..default()
+        },
+        ..default()
+    });
+}
+
+pub fn build_hangar(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    registry: &ShipPartRegistry,
+    loadout: &ShipLoadout,
+) -> Entity {
+    let hangar_entity = commands
+        .spawn(NodeBundle {
+            style: hangar_style(),
+            ..default()
+        })
+        .insert(Hangar {})
+        .with_children(|parent| {
+            // Title
+            parent
+                .spawn(NodeBundle {
+                    style: title_style(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    spawn_button_text(parent, "Hangar", get_title_text_style(asset_server));
+                });
+            // ==== Loadout Rows ====
+            for row in LoadoutRow::ALL {
+                parent
+                    .spawn(NodeBundle {
+                        style: row_style(),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: small_button_style(),
+                                    background_color: NORMAL_BUTTON_COLOR.into(),
+                                    ..default()
+                                },
+                                LoadoutButton { row, step: -1 },
+                            ))
+                            .with_children(|parent| {
+                                spawn_button_text(parent, "<", get_row_text_style(asset_server));
+                            });
+                        parent
+                            .spawn(NodeBundle {
+                                style: part_label_style(),
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    TextBundle {
+                                        text: Text::from_section(
+                                            row_label(row, loadout, registry),
+                                            get_row_text_style(asset_server),
+                                        ),
+                                        ..default()
+                                    },
+                                    LoadoutText { row },
+                                ));
+                            });
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: small_button_style(),
+                                    background_color: NORMAL_BUTTON_COLOR.into(),
+                                    ..default()
+                                },
+                                LoadoutButton { row, step: 1 },
+                            ))
+                            .with_children(|parent| {
+                                spawn_button_text(parent, ">", get_row_text_style(asset_server));
+                            });
+                    });
+            }
+            // ==== Stats ====
+            parent.spawn((
+                TextBundle {
+                    text: Text::from_section(
+                        stats_label(loadout, registry),
+                        get_row_text_style(asset_server),
+                    ),
+                    ..default()
+                },
+                StatsText {},
+            ));
+            // ==== Launch / Back Buttons ====
+            parent
+                .spawn(NodeBundle {
+                    style: row_style(),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style(),
+                                background_color: NORMAL_BUTTON_COLOR.into(),
+                                ..default()
+                            },
+                            LaunchButton {},
+                        ))
+                        .with_children(|parent| {
+                            spawn_button_text(
+                                parent,
+                                "Launch",
+                                get_button_text_style(asset_server),
+                            );
+                        });
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: button_style(),
+                                background_color: NORMAL_BUTTON_COLOR.into(),
+                                ..default()
+                            },
+                            BackButton {},
+                        ))
+                        .with_children(|parent| {
+                            spawn_button_text(parent, "Back", get_button_text_style(asset_server));
+                        });
+                });
+        })
+        .id();
+
+    hangar_entity
+}
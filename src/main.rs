@@ -3,24 +3,34 @@ use bevy::window::PrimaryWindow;
 use bevy_xpbd_2d::prelude::*;
 
 pub mod components;
+mod controls_menu;
 mod game;
 mod game_over_menu;
+mod hangar;
 mod mainmenu;
 mod states;
 mod systems;
 
+use crate::controls_menu::ControlsMenuPlugin;
 use crate::game::*;
 use crate::game_over_menu::GameOverPlugin;
+use crate::hangar::HangarPlugin;
 use crate::mainmenu::MainMenuPlugin;
-use crate::states::AppState;
+use crate::states::{AppState, GameMode};
 use crate::systems::*;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
         .add_state::<AppState>()
+        .init_resource::<GameMode>()
         .add_systems(Startup, spawn_camera)
-        .add_plugins((MainMenuPlugin {}, GameOverPlugin {}))
+        .add_plugins((
+            MainMenuPlugin {},
+            ControlsMenuPlugin {},
+            HangarPlugin {},
+            GameOverPlugin {},
+        ))
         .add_plugins(GamePlugin {})
         .run();
 }
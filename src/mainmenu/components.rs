@@ -6,5 +6,11 @@ pub struct MainMenu {}
 #[derive(Component)]
 pub struct PlayButton {}
 
+#[derive(Component)]
+pub struct PlanetDefenceButton {}
+
+#[derive(Component)]
+pub struct ControlsButton {}
+
 #[derive(Component)]
 pub struct QuitButton {}
@@ -15,7 +15,12 @@ impl Plugin for MainMenuPlugin {
         app.add_systems(OnEnter(AppState::MainMenu), spawn_main_menu)
             .add_systems(
                 Update,
-                (interact_with_play_button, interact_with_quit_button)
+                (
+                    interact_with_play_button,
+                    interact_with_planet_defence_button,
+                    interact_with_controls_button,
+                    interact_with_quit_button,
+                )
                     .run_if(in_state(AppState::MainMenu)),
             )
             .add_systems(OnExit(AppState::MainMenu), despawn_main_menu);
@@ -1,4 +1,3 @@
-use crate::game::player::PLAYER_SHIP;
 use crate::game::sprite_loader::mapper::XMLSpriteSheetLoader;
 use crate::main;
 use crate::mainmenu::components::*;
@@ -115,6 +114,52 @@ pub fn build_main_menu(
                         ..default()
                     });
                 });
+            // ==== Planet Defence Button ====
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: button_style(),
+                        background_color: NORMAL_BUTTON_COLOR.into(),
+                        ..default()
+                    },
+                    PlanetDefenceButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text {
+                            sections: vec![TextSection::new(
+                                "Planet Defence",
+                                get_button_text_style(&asset_server),
+                            )],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
+                        ..default()
+                    });
+                });
+            // ==== Controls Button ====
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: button_style(),
+                        background_color: NORMAL_BUTTON_COLOR.into(),
+                        ..default()
+                    },
+                    ControlsButton {},
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: Text {
+                            sections: vec![TextSection::new(
+                                "Controls",
+                                get_button_text_style(&asset_server),
+                            )],
+                            alignment: TextAlignment::Center,
+                            ..default()
+                        },
+                        ..default()
+                    });
+                });
             // ==== Quit Button ====
             parent
                 .spawn((
@@ -5,6 +5,17 @@ use bevy::prelude::*;
 pub enum AppState {
     #[default]
     MainMenu,
+    ControlsMenu,
+    Hangar,
     Game,
     GameOver,
 }
+
+// Which rules the next game is played with, picked from the main menu
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    #[default]
+    Classic,
+    /// Turrets guard the planet and the game ends if the planet is destroyed
+    PlanetDefence,
+}